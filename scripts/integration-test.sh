@@ -459,6 +459,361 @@ exit_code=$?
 set -e
 assert_eq "$exit_code" "1" "non-existent tab-id = exit 1"
 
+# --- Test 18: Stacked statuses ---
+echo "--- 18. Stacked statuses: --add / --remove ---"
+close_extra_tabs
+PANE_ID=$(discover_pane_id)
+cli --clear
+cli --set-name "Stack"
+sleep 0.3
+cli --add 🔔
+sleep 0.3
+cli --add 🤖
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🔔🤖" "--add stacks statuses"
+result=$(cli --name)
+assert_eq "$result" "Stack" "base name intact with stacked statuses"
+cli --remove 🔔
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🤖" "--remove drops one status from the stack"
+cli --add 🔔
+sleep 0.3
+cli --clear
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "" "--clear removes every stacked status"
+
+# --- Test 19: Symbolic statuses ---
+echo "--- 19. Symbolic statuses / aliases ---"
+cli working
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🤖" "built-in alias 'working' sets 🤖"
+ZELLIJ_TAB_STATUS_ALIASES="review=👀" cli review
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "👀" "user alias from \$ZELLIJ_TAB_STATUS_ALIASES"
+set +e
+ZELLIJ_TAB_STATUS_ALIASES="broken" cli working 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "2" "malformed \$ZELLIJ_TAB_STATUS_ALIASES = exit 2"
+cli --clear
+sleep 0.3
+
+# --- Test 20: --list ---
+echo "--- 20. --list JSON ---"
+cli --set-name "Listed"
+cli 📋
+sleep 0.3
+result=$(zellij-tab-status --list | python3 -c "
+import sys, json
+for t in json.load(sys.stdin):
+    if $PANE_ID in t['pane_ids']:
+        print(t['status'] + '|' + t['base_name'])
+        break
+" 2>/dev/null || echo "")
+assert_eq "$result" "📋|Listed" "--list reports status and base name of our tab"
+result=$(env -u ZELLIJ_PANE_ID zellij-tab-status --list)
+assert_contains "$result" "\"base_name\"" "--list works without \$ZELLIJ_PANE_ID"
+cli --clear
+sleep 0.3
+
+# --- Test 21: --tab-name / --tab-position ---
+echo "--- 21. --tab-name / --tab-position selectors ---"
+cli --clear
+cli --set-name "ByName"
+sleep 0.3
+env -u ZELLIJ_PANE_ID zellij-tab-status --tab-name "ByName" 🏷️
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🏷️" "--tab-name targets tab by base name"
+result=$(env -u ZELLIJ_PANE_ID zellij-tab-status --tab-name "ByName" --get)
+assert_eq "$result" "🏷️" "--tab-name still matches with status set"
+result=$(env -u ZELLIJ_PANE_ID zellij-tab-status --tab-position 0 --name)
+assert_eq "$result" "ByName" "--tab-position 0 targets first tab"
+set +e
+zellij-tab-status --tab-name "NoSuchTab" --get 2>/dev/null
+exit_code=$?
+zellij-tab-status --tab-name "ByName" --tab-position 0 --get 2>/dev/null
+exit_code2=$?
+set -e
+assert_eq "$exit_code" "1" "unknown --tab-name = exit 1"
+assert_eq "$exit_code2" "2" "--tab-name with --tab-position = exit 2"
+cli --clear
+sleep 0.3
+
+# --- Test 22: Suffix placement ---
+echo "--- 22. --suffix placement ---"
+cli --clear
+cli --set-name "Tail"
+sleep 0.3
+cli --suffix 🐢
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🐢" "get_status reads suffix status"
+result=$(cli --name)
+assert_eq "$result" "Tail" "get_name strips suffix status"
+cli --set-name "Tail2"
+sleep 0.3
+result=$(zellij action list-tabs 2>/dev/null || echo "")
+assert_contains "$result" "Tail2 " "set_name keeps suffix placement"
+cli --prefix 🐢
+sleep 0.3
+result=$(cli --name)
+assert_eq "$result" "Tail2" "--prefix moves status back before the name"
+cli --clear
+sleep 0.3
+
+# --- Test 23: --session ---
+echo "--- 23. --session routing ---"
+SESSION_NAME=$(zellij list-sessions --short 2>/dev/null | head -1)
+cli --set-name "InSession"
+sleep 0.3
+result=$(zellij-tab-status --session "$SESSION_NAME" --tab-position 0 --name)
+assert_eq "$result" "InSession" "--session routes to the named session"
+set +e
+ZELLIJ_SESSION_NAME="$SESSION_NAME" zellij-tab-status --session "other-session" --get 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "2" "\$ZELLIJ_PANE_ID from another session = exit 2"
+
+# --- Test 24: --list-panes ---
+echo "--- 24. --list-panes JSON ---"
+result=$(zellij-tab-status --list-panes | python3 -c "
+import sys, json
+print(json.load(sys.stdin)['$PANE_ID']['tab_name'])
+" 2>/dev/null || echo "")
+assert_eq "$result" "InSession" "--list-panes maps our pane to its tab"
+
+# --- Test 25: --focus ---
+echo "--- 25. --focus switches to the pane's tab ---"
+close_extra_tabs
+PANE_ID=$(discover_pane_id)
+cli --set-name "Home"
+sleep 0.3
+zellij action new-tab --name "Away"
+wait_for_tab_count 2
+sleep 0.5
+cli --focus
+sleep 0.5
+result=$(zellij action list-tabs --json 2>/dev/null | python3 -c "
+import sys, json
+print([t['name'] for t in json.load(sys.stdin) if t.get('active')][0])
+" 2>/dev/null || echo "")
+assert_eq "$result" "Home" "--focus activates the pane's tab"
+
+# --- Test 26: --max-width ---
+echo "--- 26. --max-width truncation ---"
+cli --clear
+cli --set-name "payments-service-api"
+sleep 0.3
+cli --max-width 12 🤖
+sleep 0.3
+result=$(cli --name)
+assert_eq "$result" "payments…" "--max-width truncates base name (end)"
+cli --max-width 12 --truncate start --set-name "payments-service-api"
+sleep 0.3
+result=$(cli --name)
+assert_eq "$result" "…vice-api" "--truncate start keeps the tail"
+result=$(cli --get)
+assert_eq "$result" "🤖" "status kept when truncating"
+cli --clear
+sleep 0.3
+
+# --- Test 27: --config ---
+echo "--- 27. --config ---"
+result=$(ZELLIJ_TAB_STATUS_PLACEMENT=suffix zellij-tab-status --config)
+assert_contains "$result" "\"placement\":\"suffix\"" "--config shows placement from env"
+result=$(ZELLIJ_TAB_STATUS_PLACEMNT=suffix zellij-tab-status --config)
+assert_contains "$result" "ZELLIJ_TAB_STATUS_PLACEMNT" "--config reports unknown variables"
+set +e
+ZELLIJ_TAB_STATUS_MAX_WIDTH=wide cli --get 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "2" "invalid config value = exit 2"
+
+# --- Test 28: --dry-run ---
+echo "--- 28. --dry-run ---"
+cli --clear
+sleep 0.3
+result=$(cli --dry-run 🤖)
+assert_contains "$result" "would rename tab" "--dry-run prints the rename"
+result=$(cli --get)
+assert_eq "$result" "" "--dry-run leaves the tab unchanged"
+ZELLIJ_TAB_STATUS_DRY_RUN=1 cli 🤖 >/dev/null
+result=$(cli --get)
+assert_eq "$result" "" "\$ZELLIJ_TAB_STATUS_DRY_RUN=1 leaves the tab unchanged"
+
+# --- Test 29: --if-status ---
+echo "--- 29. --if-status ---"
+cli --clear
+sleep 0.3
+cli --if-status "" 🤖
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🤖" "--if-status \"\" sets status on a tab without one"
+set +e
+cli --if-status ⏳ ✅ 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "3" "--if-status mismatch = exit 3"
+result=$(cli --get)
+assert_eq "$result" "🤖" "--if-status mismatch leaves status unchanged"
+cli --if-status working ✅
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "✅" "--if-status match (alias) applies the change"
+cli --clear
+sleep 0.3
+
+# --- Test 30: --capabilities ---
+echo "--- 30. --capabilities ---"
+result=$(zellij-tab-status --capabilities)
+assert_contains "$result" "\"schema_version\":1" "--capabilities reports schema version"
+assert_contains "$result" "\"--if-status\"" "--capabilities lists options"
+
+# --- Test 31: --focused ---
+echo "--- 31. --focused ---"
+expected=$(zellij action list-tabs --json 2>/dev/null | python3 -c "
+import sys, json
+print([t['name'] for t in json.load(sys.stdin) if t.get('active')][0])
+" 2>/dev/null || echo "")
+result=$(zellij-tab-status --focused --name)
+assert_eq "$result" "$expected" "--focused targets the active tab"
+set +e
+cli --focused --get 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "2" "--focused with --pane-id = exit 2"
+
+# --- Test 32: --toggle ---
+echo "--- 32. --toggle ---"
+cli --clear
+sleep 0.3
+cli --toggle 🔖
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🔖" "--toggle sets an absent status"
+cli --add 🤖
+sleep 0.3
+cli --toggle 🔖
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🤖" "--toggle removes only its own status from the stack"
+cli --clear
+sleep 0.3
+
+# --- Test 33: --cycle ---
+echo "--- 33. --cycle ---"
+cli --clear
+sleep 0.3
+for expected in "🔴" "🟡" ""; do
+    cli --cycle "🔴,🟡"
+    sleep 0.3
+    result=$(cli --get)
+    assert_eq "$result" "$expected" "--cycle advances to '$expected'"
+done
+
+# --- Test 34: --all ---
+echo "--- 34. --all ---"
+close_extra_tabs
+PANE_ID=$(discover_pane_id)
+zellij action new-tab --name "bulk-tab" 2>/dev/null
+wait_for_tab_count 2
+zellij action go-to-tab 1 2>/dev/null
+sleep 0.3
+zellij-tab-status --tab-name "bulk-tab" ❌
+cli 🤖
+sleep 0.3
+zellij-tab-status --all --if-status 🤖 ✅
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "✅" "--all --if-status updates matching tab"
+result=$(zellij-tab-status --tab-name "bulk-tab" --get)
+assert_eq "$result" "❌" "--all --if-status skips other tabs"
+zellij-tab-status --all --clear
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "" "--all --clear clears this tab"
+result=$(zellij-tab-status --tab-name "bulk-tab" --get)
+assert_eq "$result" "" "--all --clear reaches every tab"
+close_extra_tabs
+
+# --- Test 35: --tab-filter ---
+echo "--- 35. --tab-filter ---"
+close_extra_tabs
+PANE_ID=$(discover_pane_id)
+cli --set-name "svc-home"
+zellij action new-tab --name "svc-api" 2>/dev/null
+wait_for_tab_count 2
+zellij action new-tab --name "other" 2>/dev/null
+wait_for_tab_count 3
+zellij action go-to-tab 1 2>/dev/null
+sleep 0.3
+zellij-tab-status --tab-filter "svc-*" ❌
+sleep 0.3
+result=$(zellij-tab-status --tab-name "svc-api" --get)
+assert_eq "$result" "❌" "--tab-filter updates matching tab"
+result=$(cli --get)
+assert_eq "$result" "❌" "--tab-filter updates every matching tab"
+result=$(zellij-tab-status --tab-name "other" --get)
+assert_eq "$result" "" "--tab-filter skips non-matching tab"
+set +e
+zellij-tab-status --tab-filter "nothing-*" --clear 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "1" "--tab-filter without matches = exit 1"
+cli --clear
+close_extra_tabs
+
+# --- Test 36: input sanitizing ---
+echo "--- 36. input sanitizing ---"
+cli --set-name $'multi\nline\e[31m' 2>/dev/null
+sleep 0.3
+result=$(cli --name)
+assert_eq "$result" "multi line[31m" "control characters removed from --set-name"
+cli --set-name "Test"
+sleep 0.3
+
+# --- Test 37: status marker in input ---
+echo "--- 37. status marker in input ---"
+# Raw UTF-8 bytes: $'\u2063' is only expanded under a UTF-8 locale
+marker=$(printf '\xe2\x81\xa3')
+set +e
+cli --set-name "deploy ${marker}🚀" 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "2" "U+2063 in --set-name = exit 2"
+result=$(cli --name)
+assert_eq "$result" "Test" "name unchanged after rejected input"
+
+# --- Test 38: status priority ---
+echo "--- 38. status priority ---"
+cli --clear
+sleep 0.3
+export ZELLIJ_TAB_STATUS_PRIORITY="error,input,working"
+cli ❌
+sleep 0.3
+set +e
+cli 🤖 2>/dev/null
+exit_code=$?
+set -e
+assert_eq "$exit_code" "3" "lower-priority status = exit 3"
+result=$(cli --get)
+assert_eq "$result" "❌" "higher-priority status kept"
+cli --clear
+sleep 0.3
+cli 🤖
+sleep 0.3
+result=$(cli --get)
+assert_eq "$result" "🤖" "any status can be set after --clear"
+unset ZELLIJ_TAB_STATUS_PRIORITY
+cli --clear
+sleep 0.3
+
 # --- Summary ---
 echo ""
 echo "==============================="
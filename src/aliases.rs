@@ -0,0 +1,173 @@
+use crate::sanitize;
+use crate::tab_name;
+
+/// Built-in symbolic statuses. User aliases with the same name take precedence.
+pub const DEFAULTS: &[(&str, &str)] = &[
+    ("working", "🤖"),
+    ("waiting", "⏳"),
+    ("input", "✋"),
+    ("success", "✅"),
+    ("error", "❌"),
+    ("warning", "⚠️"),
+    ("building", "🔨"),
+    ("testing", "🧪"),
+    ("deploying", "🚀"),
+];
+
+/// Parse an alias spec like `working=🤖, error=❌` into (name, emoji) pairs.
+/// Whitespace around names and emojis is ignored, empty entries are skipped.
+/// Emojis with control characters or the status MARKER are rejected.
+pub fn parse(spec: &str) -> Result<Vec<(String, String)>, String> {
+    let mut aliases = Vec::new();
+    for entry in spec.split(',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let (name, emoji) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("invalid alias '{}' (expected name=emoji)", entry))?;
+        let (name, emoji) = (name.trim(), emoji.trim());
+        if name.is_empty() || emoji.is_empty() {
+            return Err(format!("invalid alias '{}' (expected name=emoji)", entry));
+        }
+        sanitize::check_marker(emoji).map_err(|e| format!("invalid alias '{}': {}", name, e))?;
+        if emoji.chars().any(char::is_control) {
+            return Err(format!(
+                "invalid alias '{}': {:?} contains control characters",
+                name, emoji
+            ));
+        }
+        aliases.push((name.to_string(), emoji.to_string()));
+    }
+    Ok(aliases)
+}
+
+/// Resolve a symbolic status to its emoji. User aliases win over DEFAULTS.
+/// Values that are not a known alias are returned unchanged.
+pub fn resolve<'a>(value: &'a str, aliases: &'a [(String, String)]) -> &'a str {
+    if let Some((_, emoji)) = aliases.iter().rev().find(|(name, _)| name == value) {
+        return emoji;
+    }
+    DEFAULTS
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, emoji)| *emoji)
+        .unwrap_or(value)
+}
+
+/// Parse a comma-separated list of statuses, e.g. `❌,🔔` or `error,input`
+/// (`$ZELLIJ_TAB_STATUS_BELL_ON`, `$ZELLIJ_TAB_STATUS_PRIORITY`).
+/// Symbolic names are resolved like any other status input; each entry keeps
+/// its first grapheme, empty entries are skipped.
+pub fn parse_statuses(spec: &str, aliases: &[(String, String)]) -> Vec<String> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| tab_name::first_grapheme(resolve(s, aliases)).to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(n, e)| (n.to_string(), e.to_string()))
+            .collect()
+    }
+
+    // ==================== parse ====================
+
+    #[test]
+    fn test_parse_single() {
+        assert_eq!(parse("working=🤖").unwrap(), pairs(&[("working", "🤖")]));
+    }
+
+    #[test]
+    fn test_parse_multiple_with_spaces() {
+        assert_eq!(
+            parse(" working = 🤖 , error=❌ ").unwrap(),
+            pairs(&[("working", "🤖"), ("error", "❌")])
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse(" , ,").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_missing_equals() {
+        assert!(parse("working").is_err());
+    }
+
+    #[test]
+    fn test_parse_empty_name_or_emoji() {
+        assert!(parse("=🤖").is_err());
+        assert!(parse("working=").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_control_characters() {
+        assert!(parse("x=\u{1b}[31m").is_err());
+        assert!(parse("x=🤖\u{7}").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_marker() {
+        assert!(parse("x=\u{2063}").is_err());
+        assert!(parse("x=🤖\u{2063}").is_err());
+    }
+
+    // ==================== resolve ====================
+
+    #[test]
+    fn test_resolve_default() {
+        assert_eq!(resolve("working", &[]), "🤖");
+        assert_eq!(resolve("error", &[]), "❌");
+    }
+
+    #[test]
+    fn test_resolve_user_overrides_default() {
+        let aliases = pairs(&[("working", "⚙️")]);
+        assert_eq!(resolve("working", &aliases), "⚙️");
+    }
+
+    #[test]
+    fn test_resolve_last_user_alias_wins() {
+        let aliases = pairs(&[("busy", "🤖"), ("busy", "🔥")]);
+        assert_eq!(resolve("busy", &aliases), "🔥");
+    }
+
+    #[test]
+    fn test_resolve_unknown_passthrough() {
+        assert_eq!(resolve("🎯", &[]), "🎯");
+        assert_eq!(resolve("Working", &[]), "Working");
+    }
+
+    // ==================== parse_statuses ====================
+
+    #[test]
+    fn test_parse_statuses() {
+        assert_eq!(parse_statuses("❌, 🔔 ,,", &[]), vec!["❌", "🔔"]);
+        assert!(parse_statuses("", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_statuses_first_grapheme() {
+        assert_eq!(parse_statuses("🇺🇸x", &[]), vec!["🇺🇸"]);
+    }
+
+    #[test]
+    fn test_parse_statuses_aliases() {
+        let aliases = pairs(&[("review", "👀")]);
+        assert_eq!(
+            parse_statuses("error, input,review", &aliases),
+            vec!["❌", "✋", "👀"]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::config;
+
+/// Bumped when the JSON printed by `--list`, `--list-panes`, `--config` or
+/// `--capabilities` changes in a way that breaks existing consumers.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Commands (long form), in the order shown by `--help`.
+pub const COMMANDS: &[&str] = &[
+    "--get",
+    "--name",
+    "--set-name",
+    "--clear",
+    "--add",
+    "--remove",
+    "--toggle",
+    "--cycle",
+    "--focus",
+    "--list",
+    "--list-panes",
+    "--config",
+    "--capabilities",
+    "--version",
+    "--help",
+];
+
+/// Options that modify a command.
+pub const OPTIONS: &[&str] = &[
+    "--pane-id",
+    "--tab-id",
+    "--tab-name",
+    "--tab-position",
+    "--focused",
+    "--all",
+    "--tab-filter",
+    "--session",
+    "--notify",
+    "--max-width",
+    "--truncate",
+    "--prefix",
+    "--suffix",
+    "--if-status",
+    "--dry-run",
+];
+
+/// Environment variables read by `Config::from_env()`.
+pub const ENV_VARS: &[&str] = &[
+    config::ENV_ALIASES,
+    config::ENV_PLACEMENT,
+    config::ENV_BELL_ON,
+    config::ENV_MAX_WIDTH,
+    config::ENV_TRUNCATE,
+    config::ENV_DRY_RUN,
+    config::ENV_PRIORITY,
+];
+
+/// What this build supports, printed by `--capabilities` so scripts can feature-detect.
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct Capabilities {
+    pub version: &'static str,
+    pub schema_version: u32,
+    pub commands: &'static [&'static str],
+    pub options: &'static [&'static str],
+    pub env: &'static [&'static str],
+}
+
+pub fn current() -> Capabilities {
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        schema_version: SCHEMA_VERSION,
+        commands: COMMANDS,
+        options: OPTIONS,
+        env: ENV_VARS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_vars_are_recognized_by_config() {
+        for var in ENV_VARS {
+            let sample = match *var {
+                config::ENV_ALIASES => "bot=🤖",
+                config::ENV_PLACEMENT => "suffix",
+                config::ENV_BELL_ON => "❌",
+                config::ENV_MAX_WIDTH => "20",
+                config::ENV_TRUNCATE => "middle",
+                config::ENV_DRY_RUN => "1",
+                config::ENV_PRIORITY => "❌,✋,🤖",
+                other => panic!("no sample value for {}", other),
+            };
+            let config = config::Config::from_vars([(var.to_string(), sample.to_string())])
+                .unwrap_or_else(|e| panic!("{}={}: {:?}", var, sample, e));
+            assert!(config.unknown_vars.is_empty(), "{}", var);
+        }
+    }
+
+    #[test]
+    fn test_no_duplicate_flags() {
+        let mut flags: Vec<&str> = COMMANDS.iter().chain(OPTIONS).copied().collect();
+        flags.sort_unstable();
+        flags.dedup();
+        assert_eq!(flags.len(), COMMANDS.len() + OPTIONS.len());
+    }
+}
@@ -0,0 +1,475 @@
+use crate::tab_name::{self, Placement};
+use crate::target;
+use crate::truncate::TruncateMode;
+use crate::zellij_api::{TabEntry, ZellijApi};
+
+/// What to do, with the value the command operates on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    GetStatus,
+    GetName,
+    Rename(Rename),
+    Focus,
+    List,
+    ListPanes,
+    ShowConfig,
+    Capabilities,
+}
+
+impl Command {
+    /// Commands that rename the target tab.
+    pub fn renames_tab(&self) -> bool {
+        matches!(self, Command::Rename(_))
+    }
+}
+
+/// Commands that rename the target tab.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rename {
+    /// Status value as given (alias names are resolved before execution)
+    Set(String),
+    Add(String),
+    Toggle(String),
+    /// Statuses in cycle order
+    Cycle(Vec<String>),
+    Remove(String),
+    Clear,
+    SetName(String),
+}
+
+/// Settings shared by every rename in one invocation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenameOptions<'a> {
+    pub placement: Option<Placement>,
+    /// Width limit for names written by everything except --remove / --clear
+    pub fit: Option<(usize, TruncateMode)>,
+    pub dry_run: bool,
+    /// Statuses from highest to lowest priority (`$ZELLIJ_TAB_STATUS_PRIORITY`)
+    pub priority: &'a [String],
+}
+
+/// What `apply_rename()` did to one tab.
+#[derive(Debug, PartialEq)]
+pub enum RenameOutcome {
+    /// The command leaves the name as it is, nothing was sent
+    Unchanged,
+    /// Dry-run: the name that would have been written
+    DryRun(String),
+    /// Refused: the tab shows `current`, which has a higher priority than `status`
+    Outranked { current: String, status: String },
+    /// The tab was renamed. `applied` is the status the command set, if any
+    /// (it triggers --notify and the bell).
+    Renamed {
+        new_name: String,
+        applied: Option<String>,
+    },
+}
+
+/// Compute the new name for a renaming command.
+/// Also returns the status it applied, which triggers --notify and the bell.
+pub fn compute_rename(
+    rename: &Rename,
+    name: &str,
+    options: &RenameOptions,
+) -> (String, Option<String>) {
+    let placement = options.placement;
+    let new_name = match rename {
+        Rename::Set(emoji) => match placement {
+            Some(placement) => tab_name::set_status_at(name, emoji, placement),
+            None => tab_name::set_status(name, emoji),
+        },
+        Rename::Add(emoji) => match placement {
+            Some(placement) => tab_name::add_status_at(name, emoji, placement),
+            None => tab_name::add_status(name, emoji),
+        },
+        Rename::Toggle(emoji) => match placement {
+            Some(placement) => tab_name::toggle_status_at(name, emoji, placement),
+            None => tab_name::toggle_status(name, emoji),
+        },
+        Rename::Cycle(cycle) => match placement {
+            Some(placement) => tab_name::cycle_status_at(name, cycle, placement),
+            None => tab_name::cycle_status(name, cycle),
+        },
+        Rename::SetName(new_base) => tab_name::set_name(name, new_base),
+        Rename::Remove(emoji) => return (tab_name::remove_status(name, emoji), None),
+        Rename::Clear => return (tab_name::clear_status(name), None),
+    };
+    let new_name = fit_to_width(new_name, options.fit);
+
+    let applied = match rename {
+        Rename::Set(emoji) | Rename::Add(emoji) | Rename::Toggle(emoji) => {
+            let status = tab_name::first_grapheme(emoji);
+            tab_name::get_statuses(&new_name)
+                .contains(&status)
+                .then(|| status.to_string())
+        }
+        Rename::Cycle(_) => {
+            Some(tab_name::get_status(&new_name).to_string()).filter(|s| !s.is_empty())
+        }
+        _ => None,
+    };
+    (new_name, applied)
+}
+
+fn fit_to_width(new_name: String, fit: Option<(usize, TruncateMode)>) -> String {
+    match fit {
+        Some((width, mode)) => tab_name::fit_width(&new_name, width, mode),
+        None => new_name,
+    }
+}
+
+/// Rename one tab for a renaming command. Nothing is sent when the name
+/// would not change or in dry-run mode.
+pub fn apply_rename(
+    api: &dyn ZellijApi,
+    rename: &Rename,
+    tab: &TabEntry,
+    options: &RenameOptions,
+) -> Result<RenameOutcome, String> {
+    let (new_name, applied) = compute_rename(rename, &tab.name, options);
+    if new_name == tab.name {
+        return Ok(RenameOutcome::Unchanged);
+    }
+    if let Some(status) = applied.as_deref() {
+        if let Some(current) = outranked_by(&tab.name, status, options.priority) {
+            return Ok(RenameOutcome::Outranked {
+                current: current.to_string(),
+                status: status.to_string(),
+            });
+        }
+    }
+    if options.dry_run {
+        return Ok(RenameOutcome::DryRun(new_name));
+    }
+    api.rename_tab(tab.tab_id, &new_name)?;
+    Ok(RenameOutcome::Renamed { new_name, applied })
+}
+
+/// The status in `name` that has a higher priority than `status`, if any.
+/// `priority` lists statuses from highest to lowest; unlisted statuses rank
+/// below all listed ones, so an empty list never refuses anything.
+pub fn outranked_by<'a>(name: &'a str, status: &str, priority: &[String]) -> Option<&'a str> {
+    let rank = |s: &str| {
+        priority
+            .iter()
+            .position(|p| p == s)
+            .unwrap_or(priority.len())
+    };
+    let new_rank = rank(status);
+    tab_name::get_statuses(name)
+        .into_iter()
+        .find(|current| rank(current) < new_rank)
+}
+
+/// Whether the tab's whole status-block equals `--if-status` (always true without it).
+pub fn status_matches(name: &str, if_status: Option<&str>) -> bool {
+    if_status.is_none_or(|expected| tab_name::get_status(name) == expected)
+}
+
+/// Tabs a bulk rename (`--all` / `--tab-filter`) applies to: base name matches
+/// the `--tab-filter` glob, and status matches `--if-status` (which selects
+/// tabs here instead of failing). Errors if the glob matches no tab at all.
+pub fn select_tabs(
+    mut tabs: Vec<TabEntry>,
+    tab_filter: Option<&str>,
+    if_status: Option<&str>,
+) -> Result<Vec<TabEntry>, String> {
+    if let Some(pattern) = tab_filter {
+        tabs.retain(|tab| target::glob_match(pattern, tab_name::get_name(&tab.name)));
+        if tabs.is_empty() {
+            return Err(format!("No tab name matches '{}'", pattern));
+        }
+    }
+    tabs.retain(|tab| status_matches(&tab.name, if_status));
+    Ok(tabs)
+}
+
+/// Focus a tab and return its 0-based position. Nothing is sent in dry-run mode.
+pub fn focus_tab(api: &dyn ZellijApi, tab: &TabEntry, dry_run: bool) -> Result<usize, String> {
+    let position = tab
+        .position
+        .ok_or_else(|| format!("Tab ID {} has no position in list-tabs output", tab.tab_id))?;
+    if !dry_run {
+        api.go_to_tab(position)?;
+    }
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tab_name::MARKER;
+    use crate::zellij_api::fake::{tab, FakeZellij};
+
+    fn names(tabs: &[TabEntry]) -> Vec<&str> {
+        tabs.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    // ==================== compute_rename ====================
+
+    #[test]
+    fn test_compute_rename_applied_status() {
+        let options = RenameOptions::default();
+        let set = Rename::Set("🤖".to_string());
+        assert_eq!(
+            compute_rename(&set, "Work", &options),
+            (format!("{}🤖 Work", MARKER), Some("🤖".to_string()))
+        );
+        // Toggling off applies nothing
+        let toggle = Rename::Toggle("🤖".to_string());
+        assert_eq!(
+            compute_rename(&toggle, &format!("{}🤖 Work", MARKER), &options),
+            ("Work".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_compute_rename_cycle_applied_status() {
+        let cycle = Rename::Cycle(vec!["🤖".to_string(), "✅".to_string()]);
+        let (new_name, applied) = compute_rename(
+            &cycle,
+            &format!("{}🤖 Work", MARKER),
+            &RenameOptions::default(),
+        );
+        assert_eq!(new_name, format!("{}✅ Work", MARKER));
+        assert_eq!(applied, Some("✅".to_string()));
+    }
+
+    #[test]
+    fn test_compute_rename_placement() {
+        let options = RenameOptions {
+            placement: Some(Placement::Suffix),
+            ..RenameOptions::default()
+        };
+        let (new_name, _) = compute_rename(&Rename::Set("🤖".to_string()), "Work", &options);
+        assert_eq!(new_name, format!("Work {}🤖", MARKER));
+    }
+
+    #[test]
+    fn test_compute_rename_fit_skips_remove_and_clear() {
+        let options = RenameOptions {
+            fit: Some((5, TruncateMode::End)),
+            ..RenameOptions::default()
+        };
+        let name = format!("{}🤖✅ payments", MARKER);
+        let (new_name, _) =
+            compute_rename(&Rename::SetName("payments".to_string()), &name, &options);
+        assert_eq!(new_name, format!("{}🤖✅ p…", MARKER));
+        let (new_name, _) = compute_rename(&Rename::Remove("✅".to_string()), &name, &options);
+        assert_eq!(new_name, format!("{}🤖 payments", MARKER));
+        let (new_name, _) = compute_rename(&Rename::Clear, &name, &options);
+        assert_eq!(new_name, "payments");
+    }
+
+    // ==================== apply_rename ====================
+
+    #[test]
+    fn test_apply_rename_sends_rename() {
+        let api = FakeZellij::new(&[], &[(0, "A"), (4, "B")]);
+        let tab = api.get_tab(4).unwrap();
+        let outcome = apply_rename(
+            &api,
+            &Rename::Set("🤖".to_string()),
+            &tab,
+            &RenameOptions::default(),
+        );
+        let new_name = format!("{}🤖 B", MARKER);
+        assert_eq!(
+            outcome,
+            Ok(RenameOutcome::Renamed {
+                new_name: new_name.clone(),
+                applied: Some("🤖".to_string()),
+            })
+        );
+        assert_eq!(
+            *api.calls.borrow(),
+            vec![format!("rename-tab-by-id 4 {}", new_name)]
+        );
+        assert_eq!(api.get_tab(4).map(|t| t.name), Ok(new_name));
+    }
+
+    #[test]
+    fn test_apply_rename_dry_run_sends_nothing() {
+        let api = FakeZellij::new(&[], &[(0, "A")]);
+        let tab = api.get_tab(0).unwrap();
+        let options = RenameOptions {
+            dry_run: true,
+            ..RenameOptions::default()
+        };
+        let outcome = apply_rename(&api, &Rename::Set("🤖".to_string()), &tab, &options);
+        assert_eq!(
+            outcome,
+            Ok(RenameOutcome::DryRun(format!("{}🤖 A", MARKER)))
+        );
+        assert!(api.calls.borrow().is_empty());
+        assert_eq!(api.get_tab(0).map(|t| t.name), Ok("A".to_string()));
+    }
+
+    #[test]
+    fn test_apply_rename_unchanged_sends_nothing() {
+        let api = FakeZellij::new(&[], &[(0, "A")]);
+        let tab = api.get_tab(0).unwrap();
+        let outcome = apply_rename(&api, &Rename::Clear, &tab, &RenameOptions::default());
+        assert_eq!(outcome, Ok(RenameOutcome::Unchanged));
+        assert!(api.calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_apply_rename_error() {
+        // The tab vanished between list-tabs and the rename
+        let api = FakeZellij::new(&[], &[]);
+        let outcome = apply_rename(
+            &api,
+            &Rename::Set("🤖".to_string()),
+            &tab(7, "gone", None),
+            &RenameOptions::default(),
+        );
+        assert!(outcome.is_err());
+    }
+
+    #[test]
+    fn test_apply_rename_outranked() {
+        let priority = vec!["❌".to_string(), "✋".to_string(), "🤖".to_string()];
+        let options = RenameOptions {
+            priority: &priority,
+            ..RenameOptions::default()
+        };
+        let api = FakeZellij::new(&[], &[(0, &format!("{}❌ A", MARKER))]);
+        let tab = api.get_tab(0).unwrap();
+        let outcome = apply_rename(&api, &Rename::Set("🤖".to_string()), &tab, &options);
+        assert_eq!(
+            outcome,
+            Ok(RenameOutcome::Outranked {
+                current: "❌".to_string(),
+                status: "🤖".to_string(),
+            })
+        );
+        assert!(api.calls.borrow().is_empty());
+
+        // Clearing the alert is always allowed
+        let outcome = apply_rename(&api, &Rename::Clear, &tab, &options);
+        assert!(matches!(outcome, Ok(RenameOutcome::Renamed { .. })));
+    }
+
+    #[test]
+    fn test_apply_rename_higher_priority_replaces() {
+        let priority = vec!["❌".to_string(), "🤖".to_string()];
+        let options = RenameOptions {
+            priority: &priority,
+            ..RenameOptions::default()
+        };
+        let api = FakeZellij::new(&[], &[(0, &format!("{}🤖 A", MARKER))]);
+        let tab = api.get_tab(0).unwrap();
+        let outcome = apply_rename(&api, &Rename::Set("❌".to_string()), &tab, &options);
+        assert!(matches!(outcome, Ok(RenameOutcome::Renamed { .. })));
+    }
+
+    // ==================== outranked_by ====================
+
+    #[test]
+    fn test_outranked_by() {
+        let priority = vec!["❌".to_string(), "✋".to_string(), "🤖".to_string()];
+        let name = format!("{}✋ A", MARKER);
+        assert_eq!(outranked_by(&name, "🤖", &priority), Some("✋"));
+        // Unlisted statuses rank lowest
+        assert_eq!(outranked_by(&name, "🎯", &priority), Some("✋"));
+        assert_eq!(outranked_by(&name, "✋", &priority), None);
+        assert_eq!(outranked_by(&name, "❌", &priority), None);
+        assert_eq!(outranked_by("A", "🤖", &priority), None);
+    }
+
+    #[test]
+    fn test_outranked_by_stack_and_unlisted() {
+        let priority = vec!["❌".to_string()];
+        let name = format!("{}🔔❌ A", MARKER);
+        assert_eq!(outranked_by(&name, "🤖", &priority), Some("❌"));
+        // Unlisted current statuses never block anything
+        assert_eq!(
+            outranked_by(&format!("{}🔔 A", MARKER), "🤖", &priority),
+            None
+        );
+        assert_eq!(outranked_by(&name, "🤖", &[]), None);
+    }
+
+    // ==================== select_tabs ====================
+
+    #[test]
+    fn test_select_tabs_all() {
+        let tabs = vec![tab(0, "A", None), tab(1, "B", None)];
+        assert_eq!(names(&select_tabs(tabs, None, None).unwrap()), ["A", "B"]);
+    }
+
+    #[test]
+    fn test_select_tabs_filter_on_base_name() {
+        let tabs = vec![
+            tab(0, &format!("{}🤖 api-payments", MARKER), None),
+            tab(1, "web", None),
+            tab(2, "api-users", None),
+        ];
+        let selected = select_tabs(tabs, Some("api-*"), None).unwrap();
+        assert_eq!(
+            names(&selected),
+            [format!("{}🤖 api-payments", MARKER).as_str(), "api-users"]
+        );
+    }
+
+    #[test]
+    fn test_select_tabs_filter_no_match() {
+        assert!(select_tabs(vec![tab(0, "web", None)], Some("api-*"), None).is_err());
+    }
+
+    #[test]
+    fn test_select_tabs_if_status_skips() {
+        let busy = format!("{}🤖 A", MARKER);
+        let tabs = vec![tab(0, &busy, None), tab(1, "B", None)];
+        assert_eq!(
+            names(&select_tabs(tabs.clone(), None, Some("🤖")).unwrap()),
+            [busy.as_str()]
+        );
+        assert_eq!(names(&select_tabs(tabs, None, Some("")).unwrap()), ["B"]);
+    }
+
+    #[test]
+    fn test_select_tabs_if_status_matches_nothing() {
+        // Not an error: --if-status only narrows the selection
+        let tabs = vec![tab(0, "A", None)];
+        assert!(select_tabs(tabs, None, Some("🤖")).unwrap().is_empty());
+    }
+
+    // ==================== status_matches ====================
+
+    #[test]
+    fn test_status_matches_whole_stack() {
+        let name = format!("{}🤖✅ A", MARKER);
+        assert!(status_matches(&name, None));
+        assert!(status_matches(&name, Some("🤖✅")));
+        assert!(!status_matches(&name, Some("🤖")));
+        assert!(status_matches("A", Some("")));
+    }
+
+    // ==================== focus_tab ====================
+
+    #[test]
+    fn test_focus_tab() {
+        let api = FakeZellij::new(&[], &[(0, "A"), (4, "B")]);
+        let tab = api.get_tab(4).unwrap();
+        assert_eq!(focus_tab(&api, &tab, false), Ok(1));
+        assert_eq!(*api.calls.borrow(), vec!["go-to-tab 2"]);
+        assert!(api.get_tab(4).unwrap().active);
+    }
+
+    #[test]
+    fn test_focus_tab_dry_run() {
+        let api = FakeZellij::new(&[], &[(0, "A"), (4, "B")]);
+        let tab = api.get_tab(4).unwrap();
+        assert_eq!(focus_tab(&api, &tab, true), Ok(1));
+        assert!(api.calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_focus_tab_without_position() {
+        let api = FakeZellij::new(&[], &[]);
+        assert!(focus_tab(&api, &tab(0, "A", None), false).is_err());
+        assert!(api.calls.borrow().is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use crate::aliases;
+use crate::tab_name::Placement;
+use crate::truncate::TruncateMode;
+
+/// Prefix shared by every configuration variable.
+pub const ENV_PREFIX: &str = "ZELLIJ_TAB_STATUS_";
+
+pub const ENV_ALIASES: &str = "ZELLIJ_TAB_STATUS_ALIASES";
+pub const ENV_PLACEMENT: &str = "ZELLIJ_TAB_STATUS_PLACEMENT";
+pub const ENV_BELL_ON: &str = "ZELLIJ_TAB_STATUS_BELL_ON";
+pub const ENV_MAX_WIDTH: &str = "ZELLIJ_TAB_STATUS_MAX_WIDTH";
+pub const ENV_TRUNCATE: &str = "ZELLIJ_TAB_STATUS_TRUNCATE";
+pub const ENV_DRY_RUN: &str = "ZELLIJ_TAB_STATUS_DRY_RUN";
+pub const ENV_PRIORITY: &str = "ZELLIJ_TAB_STATUS_PRIORITY";
+
+/// Typed configuration read from `ZELLIJ_TAB_STATUS_*` environment variables.
+/// Command-line flags override the corresponding fields.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Config {
+    /// User aliases (name → emoji), applied before `aliases::DEFAULTS`
+    pub aliases: Vec<(String, String)>,
+    /// Forced status placement; None keeps the existing placement
+    pub placement: Option<Placement>,
+    /// Statuses that ring the bell when applied to a background tab
+    pub bell_on: Vec<String>,
+    /// Maximum visible tab name width; None disables truncation
+    pub max_width: Option<usize>,
+    pub truncate: TruncateMode,
+    /// Print renames and focus changes instead of performing them
+    pub dry_run: bool,
+    /// Statuses from highest to lowest priority; a status is not replaced by a lower one
+    pub priority: Vec<String>,
+    /// `ZELLIJ_TAB_STATUS_*` variables that are set but not recognized (likely typos)
+    pub unknown_vars: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            aliases: Vec::new(),
+            placement: None,
+            bell_on: Vec::new(),
+            max_width: None,
+            truncate: TruncateMode::End,
+            dry_run: false,
+            priority: Vec::new(),
+            unknown_vars: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Build a config from `(name, value)` pairs, e.g. `std::env::vars()`.
+    /// Variables without the `ZELLIJ_TAB_STATUS_` prefix are ignored.
+    /// Returns every invalid value at once, one message per variable.
+    pub fn from_vars<I>(vars: I) -> Result<Config, Vec<String>>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let vars: BTreeMap<String, String> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .collect();
+        let mut config = Config::default();
+        let mut errors = Vec::new();
+        let mut bell_on_spec = None;
+        let mut priority_spec = None;
+
+        for (name, value) in &vars {
+            let result = match name.as_str() {
+                ENV_ALIASES => aliases::parse(value).map(|a| config.aliases = a),
+                ENV_PLACEMENT => Placement::parse(value).map(|p| config.placement = Some(p)),
+                ENV_BELL_ON => {
+                    bell_on_spec = Some(value);
+                    Ok(())
+                }
+                ENV_MAX_WIDTH => value
+                    .parse::<usize>()
+                    .map(|w| config.max_width = Some(w))
+                    .map_err(|_| format!("must be a non-negative integer: '{}'", value)),
+                ENV_TRUNCATE => TruncateMode::parse(value).map(|t| config.truncate = t),
+                ENV_DRY_RUN => parse_bool(value).map(|b| config.dry_run = b),
+                ENV_PRIORITY => {
+                    priority_spec = Some(value);
+                    Ok(())
+                }
+                _ => {
+                    config.unknown_vars.push(name.clone());
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                errors.push(format!("${}: {}", name, e));
+            }
+        }
+
+        // Resolved once all aliases are known, independent of variable order
+        if let Some(spec) = bell_on_spec {
+            config.bell_on = aliases::parse_statuses(spec, &config.aliases);
+        }
+        if let Some(spec) = priority_spec {
+            config.priority = aliases::parse_statuses(spec, &config.aliases);
+        }
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Build a config from the process environment.
+    /// Variables whose name or value is not valid Unicode are skipped.
+    pub fn from_env() -> Result<Config, Vec<String>> {
+        Config::from_vars(std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        }))
+    }
+}
+
+/// Parse a boolean switch: 1/true/yes/on or 0/false/no/off (case-insensitive, empty = off).
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "" | "0" | "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("expected a boolean (1/0, true/false): '{}'", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_env_is_default() {
+        assert_eq!(Config::from_vars(vars(&[])), Ok(Config::default()));
+    }
+
+    #[test]
+    fn test_ignores_unrelated_vars() {
+        let config = Config::from_vars(vars(&[("HOME", "/root"), ("ZELLIJ_PANE_ID", "3")]));
+        assert_eq!(config, Ok(Config::default()));
+    }
+
+    #[test]
+    fn test_parses_all_known_vars() {
+        let config = Config::from_vars(vars(&[
+            (ENV_ALIASES, "busy=🔥"),
+            (ENV_PLACEMENT, "suffix"),
+            (ENV_BELL_ON, "❌,🔔"),
+            (ENV_MAX_WIDTH, "24"),
+            (ENV_TRUNCATE, "middle"),
+            (ENV_DRY_RUN, "1"),
+        ]))
+        .unwrap();
+        assert_eq!(config.aliases, vec![("busy".to_string(), "🔥".to_string())]);
+        assert_eq!(config.placement, Some(Placement::Suffix));
+        assert_eq!(config.bell_on, vec!["❌", "🔔"]);
+        assert_eq!(config.max_width, Some(24));
+        assert_eq!(config.truncate, TruncateMode::Middle);
+        assert!(config.dry_run);
+        assert!(config.unknown_vars.is_empty());
+    }
+
+    #[test]
+    fn test_bell_on_resolves_aliases() {
+        let config = Config::from_vars(vars(&[
+            (ENV_BELL_ON, "error,review"),
+            (ENV_ALIASES, "review=👀"),
+        ]))
+        .unwrap();
+        assert_eq!(config.bell_on, vec!["❌", "👀"]);
+    }
+
+    #[test]
+    fn test_priority() {
+        let config = Config::from_vars(vars(&[(ENV_PRIORITY, "error, ✋,working")])).unwrap();
+        assert_eq!(config.priority, vec!["❌", "✋", "🤖"]);
+    }
+
+    #[test]
+    fn test_collects_all_errors() {
+        let errors = Config::from_vars(vars(&[
+            (ENV_PLACEMENT, "middle"),
+            (ENV_MAX_WIDTH, "wide"),
+            (ENV_TRUNCATE, "end"),
+        ]))
+        .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("$ZELLIJ_TAB_STATUS_PLACEMENT")));
+        assert!(errors
+            .iter()
+            .any(|e| e.starts_with("$ZELLIJ_TAB_STATUS_MAX_WIDTH")));
+    }
+
+    #[test]
+    fn test_reports_unknown_vars() {
+        let config = Config::from_vars(vars(&[("ZELLIJ_TAB_STATUS_PLACEMNT", "suffix")])).unwrap();
+        assert_eq!(config.unknown_vars, vec!["ZELLIJ_TAB_STATUS_PLACEMNT"]);
+        assert_eq!(config.placement, None);
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        assert_eq!(parse_bool("TRUE"), Ok(true));
+        assert_eq!(parse_bool("off"), Ok(false));
+        assert_eq!(parse_bool(""), Ok(false));
+        assert!(parse_bool("maybe").is_err());
+    }
+}
@@ -1,2 +1,11 @@
+pub mod aliases;
+pub mod capabilities;
+pub mod command;
+pub mod config;
+pub mod notify;
+pub mod sanitize;
+pub mod summary;
 pub mod tab_name;
+pub mod target;
+pub mod truncate;
 pub mod zellij_api;
@@ -1,8 +1,17 @@
 use std::env;
 use std::process;
 
-use zellij_tab_status::tab_name;
-use zellij_tab_status::zellij_api;
+use zellij_tab_status::aliases;
+use zellij_tab_status::capabilities;
+use zellij_tab_status::command::{self, Command, Rename, RenameOptions, RenameOutcome};
+use zellij_tab_status::config::Config;
+use zellij_tab_status::notify;
+use zellij_tab_status::sanitize;
+use zellij_tab_status::summary;
+use zellij_tab_status::tab_name::{self, Placement};
+use zellij_tab_status::target::{self, Target};
+use zellij_tab_status::truncate::TruncateMode;
+use zellij_tab_status::zellij_api::{self, TabEntry, ZellijApi};
 
 const HELP: &str = "\
 zellij-tab-status - Manage status emoji in zellij tab name
@@ -10,25 +19,83 @@ zellij-tab-status - Manage status emoji in zellij tab name
 Usage:
   zellij-tab-status                 Get current status (same as --get)
   zellij-tab-status <emoji>        Set status emoji
-  zellij-tab-status --clear, -c    Remove status emoji
+  zellij-tab-status --clear, -c    Remove status emoji (all stacked statuses)
+  zellij-tab-status --add, -a <emoji>     Add emoji to the status stack
+  zellij-tab-status --remove, -r <emoji>  Remove emoji from the status stack
+  zellij-tab-status --toggle, -t <emoji>  Remove emoji if present, else set it
+  zellij-tab-status --cycle <list>  Advance through a comma-separated status list,
+                                    e.g. 🔴,🟡,🟢 (none → 🔴 → 🟡 → 🟢 → none)
   zellij-tab-status --get, -g      Get current status emoji
   zellij-tab-status --get-status   Get current status emoji (alias)
   zellij-tab-status --name, -n     Get base name (without status)
   zellij-tab-status --set-name, -s <name>  Set tab name (preserving status)
+  zellij-tab-status --focus, -f    Switch to the target tab
+  zellij-tab-status --list, -l     List all tabs with their status as JSON
+  zellij-tab-status --list-panes   List terminal panes and their tabs as JSON
+  zellij-tab-status --config       Show effective configuration as JSON
+  zellij-tab-status --capabilities  Show supported commands and options as JSON
   zellij-tab-status --version, -v  Show version
   zellij-tab-status --help, -h     Show this help
 
 Options:
   --pane-id <id>    Use specific pane ID instead of $ZELLIJ_PANE_ID
-  --tab-id <id>     Use specific tab ID directly (skip pane resolution)";
+  --tab-id <id>     Use specific tab ID directly (skip pane resolution)
+  --tab-name <name> Target the tab with this base name (must be unique)
+  --tab-position <n>  Target the tab at 0-based position in the tab bar
+  --focused         Target the focused tab (e.g. from a keybinding)
+  --all             Apply a renaming command to every tab in the session
+                    (with --if-status: only tabs whose status matches)
+  --tab-filter <glob>
+                    Like --all, but only tabs whose base name matches the
+                    glob (* = any characters, ? = one character)
+  --session <name>  Operate on another Zellij session (zellij --session)
+  --notify          Show a desktop notification when the status changes
+                    (notify-send on Linux, osascript on macOS)
+  --max-width <n>   Truncate the base name so the tab name fits in n columns
+  --truncate <mode> Where to cut when truncating: start, middle, end (default)
+  --prefix          Render the status before the name: 🤖 Work (default)
+  --suffix          Render the status after the name:  Work 🤖
+  --if-status <emoji>
+                    Only rename if the current status is exactly <emoji>
+                    (empty string = no status); otherwise exit 3
+  --dry-run         Print the rename/focus that would happen instead of doing it
+
+  Tab selectors are mutually exclusive; without one, $ZELLIJ_PANE_ID is used.
+  Without --prefix/--suffix, an existing status keeps its placement.
+
+Environment (flags take precedence):
+  ZELLIJ_TAB_STATUS_ALIASES    Extra status names, e.g. working=⚙️,review=👀
+  ZELLIJ_TAB_STATUS_PLACEMENT  prefix or suffix
+  ZELLIJ_TAB_STATUS_MAX_WIDTH  Default for --max-width
+  ZELLIJ_TAB_STATUS_TRUNCATE   Default for --truncate
+  ZELLIJ_TAB_STATUS_DRY_RUN    Set to 1 to enable --dry-run
+  ZELLIJ_TAB_STATUS_BELL_ON    Statuses that ring the terminal bell when set
+  ZELLIJ_TAB_STATUS_PRIORITY   Statuses by priority, highest first, e.g. error,input,working;
+                               a lower status never replaces a higher one (exit 3)
+                               on a background tab, e.g. ❌,🔔 or error,input
+
+Status aliases:
+  <emoji> may be a symbolic name: working, waiting, input, success, error,
+  warning, building, testing, deploying, or one from ZELLIJ_TAB_STATUS_ALIASES.";
 
 fn main() {
     let args: Vec<String> = env::args().skip(1).collect();
 
     let mut pane_id_arg: Option<u32> = None;
     let mut tab_id_arg: Option<u32> = None;
-    let mut command: Option<String> = None;
-    let mut command_value: Option<String> = None;
+    let mut tab_name_arg: Option<String> = None;
+    let mut tab_position_arg: Option<usize> = None;
+    let mut focused_arg = false;
+    let mut all_arg = false;
+    let mut tab_filter_arg: Option<String> = None;
+    let mut session_arg: Option<String> = None;
+    let mut placement_arg: Option<Placement> = None;
+    let mut notify_arg = false;
+    let mut dry_run_arg = false;
+    let mut if_status_arg: Option<String> = None;
+    let mut max_width_arg: Option<usize> = None;
+    let mut truncate_arg: Option<TruncateMode> = None;
+    let mut command: Option<Command> = None;
 
     let mut i = 0;
     while i < args.len() {
@@ -63,23 +130,162 @@ fn main() {
                     process::exit(2);
                 }));
             }
+            "--tab-name" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --tab-name requires a value");
+                    process::exit(2);
+                }
+                tab_name_arg = Some(args[i].clone());
+            }
+            "--tab-position" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --tab-position requires a value");
+                    process::exit(2);
+                }
+                tab_position_arg = Some(args[i].parse::<usize>().unwrap_or_else(|_| {
+                    eprintln!("Error: --tab-position must be a non-negative integer");
+                    process::exit(2);
+                }));
+            }
+            "--focused" => {
+                focused_arg = true;
+            }
+            "--all" => {
+                all_arg = true;
+            }
+            "--tab-filter" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --tab-filter requires a value");
+                    process::exit(2);
+                }
+                tab_filter_arg = Some(args[i].clone());
+            }
+            "--session" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --session requires a value");
+                    process::exit(2);
+                }
+                session_arg = Some(args[i].clone());
+            }
+            "--notify" => {
+                notify_arg = true;
+            }
+            "--if-status" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --if-status requires a value");
+                    process::exit(2);
+                }
+                if_status_arg = Some(args[i].clone());
+            }
+            "--dry-run" => {
+                dry_run_arg = true;
+            }
+            "--max-width" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --max-width requires a value");
+                    process::exit(2);
+                }
+                max_width_arg = Some(args[i].parse::<usize>().unwrap_or_else(|_| {
+                    eprintln!("Error: --max-width must be a non-negative integer");
+                    process::exit(2);
+                }));
+            }
+            "--truncate" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --truncate requires a value");
+                    process::exit(2);
+                }
+                truncate_arg = Some(TruncateMode::parse(&args[i]).unwrap_or_else(|e| {
+                    eprintln!("Error: --truncate: {}", e);
+                    process::exit(2);
+                }));
+            }
+            "--prefix" => {
+                placement_arg = Some(Placement::Prefix);
+            }
+            "--suffix" => {
+                placement_arg = Some(Placement::Suffix);
+            }
             "--get" | "-g" | "--get-status" => {
-                command = Some("get_status".to_string());
+                command = Some(Command::GetStatus);
             }
             "--clear" | "-c" => {
-                command = Some("clear_status".to_string());
+                command = Some(Command::Rename(Rename::Clear));
+            }
+            "--add" | "-a" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --add requires a value");
+                    process::exit(2);
+                }
+                command = Some(Command::Rename(Rename::Add(args[i].clone())));
+            }
+            "--remove" | "-r" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --remove requires a value");
+                    process::exit(2);
+                }
+                command = Some(Command::Rename(Rename::Remove(args[i].clone())));
+            }
+            "--toggle" | "-t" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --toggle requires a value");
+                    process::exit(2);
+                }
+                command = Some(Command::Rename(Rename::Toggle(args[i].clone())));
+            }
+            "--cycle" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --cycle requires a value");
+                    process::exit(2);
+                }
+                let cycle: Vec<String> = args[i]
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .collect();
+                if cycle.is_empty() {
+                    eprintln!("Error: --cycle requires at least one status");
+                    process::exit(2);
+                }
+                command = Some(Command::Rename(Rename::Cycle(cycle)));
+            }
+            "--focus" | "-f" => {
+                command = Some(Command::Focus);
+            }
+            "--list" | "-l" => {
+                command = Some(Command::List);
+            }
+            "--config" => {
+                command = Some(Command::ShowConfig);
+            }
+            "--capabilities" => {
+                command = Some(Command::Capabilities);
+            }
+            "--list-panes" => {
+                command = Some(Command::ListPanes);
             }
             "--name" | "-n" => {
-                command = Some("get_name".to_string());
+                command = Some(Command::GetName);
             }
             "--set-name" | "-s" => {
-                command = Some("set_name".to_string());
                 i += 1;
                 if i >= args.len() {
                     eprintln!("Error: --set-name requires a value");
                     process::exit(2);
                 }
-                command_value = Some(args[i].clone());
+                command = Some(Command::Rename(Rename::SetName(args[i].clone())));
             }
             other => {
                 if other.starts_with('-') {
@@ -94,106 +300,333 @@ fn main() {
                     process::exit(2);
                 }
                 // Positional argument = set_status with emoji
-                command = Some("set_status".to_string());
-                command_value = Some(other.to_string());
+                command = Some(Command::Rename(Rename::Set(other.to_string())));
             }
         }
         i += 1;
     }
 
     // Default command: get_status
-    let command = command.unwrap_or_else(|| "get_status".to_string());
+    let command = command.unwrap_or(Command::GetStatus);
 
     // Validate mutually exclusive options
-    if pane_id_arg.is_some() && tab_id_arg.is_some() {
-        eprintln!("Error: --pane-id and --tab-id are mutually exclusive");
+    let selectors: Vec<Target> = [
+        pane_id_arg.map(Target::Pane),
+        tab_id_arg.map(Target::TabId),
+        tab_name_arg.map(Target::TabName),
+        tab_position_arg.map(Target::TabPosition),
+        focused_arg.then_some(Target::Focused),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if selectors.len() > 1 {
+        eprintln!(
+            "Error: --pane-id, --tab-id, --tab-name, --tab-position and --focused are mutually exclusive"
+        );
         process::exit(2);
     }
 
-    // Resolve tab_id
-    let tab_id = resolve_tab_id(pane_id_arg, tab_id_arg);
+    let config = Config::from_env().unwrap_or_else(|errors| {
+        for e in errors {
+            eprintln!("Error: {}", e);
+        }
+        process::exit(2);
+    });
+
+    let api = zellij_api::Cli {
+        session: session_arg.clone(),
+    };
+
+    // Resolve symbolic statuses to emoji
+    let resolve =
+        |value: String| sanitize_input(aliases::resolve(&value, &config.aliases).to_string());
+    let command = match command {
+        Command::Rename(rename) => Command::Rename(match rename {
+            Rename::Set(value) => Rename::Set(resolve(value)),
+            Rename::Add(value) => Rename::Add(resolve(value)),
+            Rename::Toggle(value) => Rename::Toggle(resolve(value)),
+            Rename::Cycle(values) => Rename::Cycle(values.into_iter().map(resolve).collect()),
+            Rename::Remove(value) => Rename::Remove(resolve(value)),
+            Rename::Clear => Rename::Clear,
+            Rename::SetName(name) => Rename::SetName(sanitize_input(name)),
+        }),
+        other => other,
+    };
+
+    let if_status = match if_status_arg {
+        Some(_) if !command.renames_tab() => {
+            eprintln!("Error: --if-status only applies to commands that rename the tab");
+            process::exit(2);
+        }
+        expected => expected.map(resolve),
+    };
+
+    // Session-wide commands don't target a single tab
+    match command {
+        Command::ShowConfig => {
+            print_json(&config);
+            return;
+        }
+        Command::Capabilities => {
+            print_json(&capabilities::current());
+            return;
+        }
+        Command::List => {
+            list_all_tabs(&api);
+            return;
+        }
+        Command::ListPanes => {
+            list_all_panes(&api);
+            return;
+        }
+        _ => {}
+    }
+
+    let options = RenameOptions {
+        placement: placement_arg.or(config.placement),
+        fit: max_width_arg
+            .or(config.max_width)
+            .map(|width| (width, truncate_arg.unwrap_or(config.truncate))),
+        dry_run: dry_run_arg || config.dry_run,
+        priority: &config.priority,
+    };
+    let bulk = all_arg || tab_filter_arg.is_some();
+    let apply = |rename: &Rename, tab: &TabEntry| {
+        let outcome = command::apply_rename(&api, rename, tab, &options).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        });
+        report_rename(tab, outcome, bulk, notify_arg, &config.bell_on);
+    };
+
+    if bulk {
+        let Command::Rename(rename) = &command else {
+            eprintln!("Error: --all and --tab-filter only apply to commands that rename the tab");
+            process::exit(2);
+        };
+        if !selectors.is_empty() {
+            eprintln!("Error: --all and --tab-filter cannot be combined with a tab selector");
+            process::exit(2);
+        }
+        let tabs = api
+            .list_tabs()
+            .and_then(|tabs| {
+                command::select_tabs(tabs, tab_filter_arg.as_deref(), if_status.as_deref())
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            });
+        for tab in &tabs {
+            apply(rename, tab);
+        }
+        return;
+    }
+
+    // Resolve the tab (one list-tabs call, reused below)
+    let target = selectors
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| target_from_env(session_arg.as_deref()));
+    let tab = resolve_tab(&api, target);
 
     // Execute command
-    match command.as_str() {
-        "get_status" => {
-            let name = get_current_tab_name(tab_id);
-            let status = tab_name::get_status(&name);
+    match command {
+        Command::GetStatus => {
+            let status = tab_name::get_status(&tab.name);
             println!("{}", status);
         }
-        "get_name" => {
-            let name = get_current_tab_name(tab_id);
-            let base = tab_name::get_name(&name);
+        Command::GetName => {
+            let base = tab_name::get_name(&tab.name);
             println!("{}", base);
         }
-        "set_status" => {
-            let emoji = command_value.unwrap_or_else(|| {
-                eprintln!("Error: set_status requires an emoji argument");
-                process::exit(2);
+        Command::Focus => {
+            let position = command::focus_tab(&api, &tab, options.dry_run).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                process::exit(1);
             });
-            let name = get_current_tab_name(tab_id);
-            let new_name = tab_name::set_status(&name, &emoji);
-            if new_name != name {
-                rename_tab(tab_id, &new_name);
+            if options.dry_run {
+                println!("would focus tab {} at position {}", tab.tab_id, position);
             }
         }
-        "clear_status" => {
-            let name = get_current_tab_name(tab_id);
-            let new_name = tab_name::clear_status(&name);
-            if new_name != name {
-                rename_tab(tab_id, &new_name);
-            }
+        Command::ShowConfig | Command::Capabilities | Command::List | Command::ListPanes => {
+            unreachable!("session-wide commands return early")
         }
-        "set_name" => {
-            let new_base = command_value.unwrap_or_else(|| {
-                eprintln!("Error: --set-name requires a name argument");
-                process::exit(2);
-            });
-            let name = get_current_tab_name(tab_id);
-            let new_name = tab_name::set_name(&name, &new_base);
-            if new_name != name {
-                rename_tab(tab_id, &new_name);
-            }
+        Command::Rename(rename) => {
+            check_if_status(&tab, if_status.as_deref());
+            apply(&rename, &tab);
         }
-        _ => unreachable!(),
     }
 }
 
-fn resolve_tab_id(pane_id_arg: Option<u32>, tab_id_arg: Option<u32>) -> u32 {
-    if let Some(tab_id) = tab_id_arg {
-        return tab_id;
+/// Sanitize a value that will end up in a tab name, warning on stderr if it had to change.
+/// Values containing the status marker are rejected (exit 2).
+fn sanitize_input(value: String) -> String {
+    if let Err(e) = sanitize::check_marker(&value) {
+        eprintln!("Error: {}", e);
+        process::exit(2);
+    }
+    let clean = sanitize::sanitize(&value);
+    if clean != value {
+        eprintln!(
+            "Warning: {:?} contains control characters, is not NFC-normalized or is longer than {} characters, using {:?}",
+            value,
+            sanitize::MAX_LEN,
+            clean
+        );
+    }
+    clean
+}
+
+fn target_from_env(session_arg: Option<&str>) -> Target {
+    // $ZELLIJ_PANE_ID is only meaningful in the session it came from
+    if let (Some(wanted), Ok(current)) = (session_arg, env::var("ZELLIJ_SESSION_NAME")) {
+        if wanted != current {
+            eprintln!(
+                "Error: $ZELLIJ_PANE_ID belongs to session '{}', not '{}' (use --pane-id, --tab-id, --tab-name, --tab-position or --focused)",
+                current, wanted
+            );
+            process::exit(2);
+        }
     }
 
-    let pane_id = if let Some(id) = pane_id_arg {
-        id
-    } else {
-        match env::var("ZELLIJ_PANE_ID") {
-            Ok(val) => val.parse::<u32>().unwrap_or_else(|_| {
-                eprintln!("Error: $ZELLIJ_PANE_ID is not a valid integer: '{}'", val);
-                process::exit(2);
-            }),
-            Err(_) => {
-                eprintln!("Error: $ZELLIJ_PANE_ID not set (not running inside Zellij?)");
-                process::exit(2);
-            }
+    match env::var("ZELLIJ_PANE_ID") {
+        Ok(val) => Target::Pane(val.parse::<u32>().unwrap_or_else(|_| {
+            eprintln!("Error: $ZELLIJ_PANE_ID is not a valid integer: '{}'", val);
+            process::exit(2);
+        })),
+        Err(_) => {
+            eprintln!("Error: $ZELLIJ_PANE_ID not set (not running inside Zellij?)");
+            process::exit(2);
         }
+    }
+}
+
+fn resolve_tab(api: &dyn ZellijApi, target: Target) -> TabEntry {
+    let result = match target {
+        Target::Pane(pane_id) => api
+            .resolve_tab_id(pane_id)
+            .and_then(|tab_id| api.get_tab(tab_id)),
+        Target::TabId(tab_id) => api.get_tab(tab_id),
+        Target::TabName(name) => api
+            .list_tabs()
+            .and_then(|tabs| target::find_by_name(&tabs, &name).cloned()),
+        Target::TabPosition(position) => api
+            .list_tabs()
+            .and_then(|tabs| target::find_by_position(&tabs, position).cloned()),
+        Target::Focused => api
+            .list_tabs()
+            .and_then(|tabs| target::find_focused(&tabs).cloned()),
     };
 
-    zellij_api::resolve_tab_id(pane_id).unwrap_or_else(|e| {
+    result.unwrap_or_else(|e| {
         eprintln!("Error: {}", e);
         process::exit(1);
     })
 }
 
-fn get_current_tab_name(tab_id: u32) -> String {
-    zellij_api::get_tab_name(tab_id).unwrap_or_else(|e| {
+fn list_tabs_and_panes(api: &dyn ZellijApi) -> (Vec<TabEntry>, Vec<zellij_api::PaneEntry>) {
+    let tabs = api.list_tabs().unwrap_or_else(|e| {
         eprintln!("Error: {}", e);
         process::exit(1);
-    })
-}
-
-fn rename_tab(tab_id: u32, new_name: &str) {
-    zellij_api::rename_tab(tab_id, new_name).unwrap_or_else(|e| {
+    });
+    let panes = api.list_panes().unwrap_or_else(|e| {
         eprintln!("Error: {}", e);
         process::exit(1);
     });
+    (tabs, panes)
+}
+
+fn print_json<T: serde::Serialize>(value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error: Failed to serialize JSON: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn list_all_tabs(api: &dyn ZellijApi) {
+    let (tabs, panes) = list_tabs_and_panes(api);
+    print_json(&summary::summarize(&tabs, &panes));
+}
+
+fn list_all_panes(api: &dyn ZellijApi) {
+    let (tabs, panes) = list_tabs_and_panes(api);
+    print_json(&summary::summarize_panes(&tabs, &panes));
+}
+
+/// With `--if-status`, exits with code 3 when the tab's current status differs from the expected one.
+fn check_if_status(tab: &TabEntry, if_status: Option<&str>) {
+    if !command::status_matches(&tab.name, if_status) {
+        eprintln!(
+            "Error: tab {} status is '{}', expected '{}'",
+            tab.tab_id,
+            tab_name::get_status(&tab.name),
+            if_status.unwrap_or_default()
+        );
+        process::exit(3);
+    }
+}
+
+/// Print a dry-run rename, or notify / ring the bell when a rename applied a status.
+/// A rename refused by `$ZELLIJ_TAB_STATUS_PRIORITY` exits 3, like `--if-status`;
+/// in bulk mode the tab is skipped with a warning instead.
+fn report_rename(
+    tab: &TabEntry,
+    outcome: RenameOutcome,
+    bulk: bool,
+    notify: bool,
+    bell_on: &[String],
+) {
+    match outcome {
+        RenameOutcome::Unchanged | RenameOutcome::Renamed { applied: None, .. } => {}
+        RenameOutcome::DryRun(new_name) => {
+            println!("would rename tab {} to '{}'", tab.tab_id, new_name);
+        }
+        RenameOutcome::Outranked { current, status } => {
+            let message = format!(
+                "tab {} has higher-priority status '{}', not setting '{}'",
+                tab.tab_id, current, status
+            );
+            if bulk {
+                eprintln!("Warning: {}", message);
+            } else {
+                eprintln!("Error: {}", message);
+                process::exit(3);
+            }
+        }
+        RenameOutcome::Renamed {
+            new_name,
+            applied: Some(status),
+        } => {
+            if notify {
+                notify_status_change(&new_name);
+            }
+            ring_bell_if_configured(tab, &status, bell_on);
+        }
+    }
+}
+
+/// Show a desktop notification for a rename that already happened.
+/// A failing notifier is only a warning: the rename itself succeeded.
+fn notify_status_change(new_name: &str) {
+    let body = format!(
+        "{} {}",
+        tab_name::get_status(new_name),
+        tab_name::get_name(new_name)
+    );
+    if let Err(e) = notify::send(notify::TITLE, &body) {
+        eprintln!("Warning: {}", e);
+    }
+}
+
+/// `tab` is the list-tabs entry read before the rename; its `active` flag decides.
+fn ring_bell_if_configured(tab: &TabEntry, emoji: &str, bell_on: &[String]) {
+    let status = tab_name::first_grapheme(emoji);
+    if notify::should_ring(status, bell_on, tab.active) {
+        notify::ring_bell();
+    }
 }
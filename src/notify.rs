@@ -0,0 +1,123 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::Command;
+
+/// Title shown on every desktop notification.
+pub const TITLE: &str = "zellij-tab-status";
+
+/// Build the notifier command for the given OS (`std::env::consts::OS`).
+/// Returns None on platforms without a known notifier.
+pub fn notifier_command(os: &str, title: &str, body: &str) -> Option<(String, Vec<String>)> {
+    match os {
+        "macos" => Some((
+            "osascript".to_string(),
+            vec![
+                "-e".to_string(),
+                format!(
+                    "display notification \"{}\" with title \"{}\"",
+                    applescript_escape(body),
+                    applescript_escape(title)
+                ),
+            ],
+        )),
+        "linux" | "freebsd" | "openbsd" | "netbsd" => Some((
+            "notify-send".to_string(),
+            vec![title.to_string(), body.to_string()],
+        )),
+        _ => None,
+    }
+}
+
+/// Escape a string for use inside an AppleScript string literal.
+fn applescript_escape(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Show a desktop notification via `notify-send` (Linux/BSD) or `osascript` (macOS).
+pub fn send(title: &str, body: &str) -> Result<(), String> {
+    let os = std::env::consts::OS;
+    let (bin, args) = notifier_command(os, title, body)
+        .ok_or_else(|| format!("Desktop notifications are not supported on {}", os))?;
+
+    let output = Command::new(&bin)
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to run '{}': {}", bin, e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "'{}' failed (exit {}): {}",
+            bin, output.status, stderr
+        ));
+    }
+
+    Ok(())
+}
+
+/// The bell rings when a status from `bell_on` is applied to a tab the user isn't looking at.
+pub fn should_ring(status: &str, bell_on: &[String], tab_active: bool) -> bool {
+    !tab_active && bell_on.iter().any(|s| s == status)
+}
+
+/// Ring the terminal bell by writing BEL to the controlling terminal.
+/// Does nothing when there is no controlling terminal (e.g. detached hooks).
+pub fn ring_bell() {
+    if let Ok(mut tty) = OpenOptions::new().write(true).open("/dev/tty") {
+        let _ = tty.write_all(b"\x07");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases;
+
+    // ==================== bell ====================
+
+    #[test]
+    fn test_should_ring_background_tab() {
+        let bell_on = aliases::parse_statuses("❌,🔔", &[]);
+        assert!(should_ring("❌", &bell_on, false));
+        assert!(!should_ring("🤖", &bell_on, false));
+    }
+
+    #[test]
+    fn test_should_ring_not_on_focused_tab() {
+        let bell_on = aliases::parse_statuses("❌", &[]);
+        assert!(!should_ring("❌", &bell_on, true));
+    }
+
+    // ==================== desktop notifications ====================
+
+    #[test]
+    fn test_notifier_linux() {
+        let (bin, args) = notifier_command("linux", "T", "🤖 Work").unwrap();
+        assert_eq!(bin, "notify-send");
+        assert_eq!(args, vec!["T", "🤖 Work"]);
+    }
+
+    #[test]
+    fn test_notifier_macos() {
+        let (bin, args) = notifier_command("macos", "T", "✅ Build").unwrap();
+        assert_eq!(bin, "osascript");
+        assert_eq!(
+            args,
+            vec!["-e", "display notification \"✅ Build\" with title \"T\""]
+        );
+    }
+
+    #[test]
+    fn test_notifier_macos_escapes_quotes() {
+        let (_, args) = notifier_command("macos", "T", "say \"hi\" \\o/").unwrap();
+        assert_eq!(
+            args[1],
+            "display notification \"say \\\"hi\\\" \\\\o/\" with title \"T\""
+        );
+    }
+
+    #[test]
+    fn test_notifier_unsupported() {
+        assert!(notifier_command("windows", "T", "x").is_none());
+    }
+}
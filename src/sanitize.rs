@@ -0,0 +1,112 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::tab_name::MARKER;
+
+/// Longest accepted tab name or status value, in grapheme clusters.
+pub const MAX_LEN: usize = 256;
+
+/// Make user input safe to put into a tab name:
+/// newlines and tabs become spaces, other control characters (e.g. ESC of a
+/// terminal escape sequence) are removed, the text is NFC-normalized (so "e" +
+/// U+0301 and "é" give the same tab name), and the result is cut to MAX_LEN
+/// grapheme clusters.
+pub fn sanitize(input: &str) -> String {
+    let cleaned: String = input
+        .chars()
+        .filter_map(|c| match c {
+            '\n' | '\r' | '\t' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .nfc()
+        .collect();
+    match cleaned.grapheme_indices(true).nth(MAX_LEN) {
+        Some((end, _)) => cleaned[..end].to_string(),
+        None => cleaned,
+    }
+}
+
+/// Refuse input containing the status MARKER (U+2063): a name like
+/// "Work \u{2063}🤖" would be parsed back as base "Work" with status 🤖.
+pub fn check_marker(input: &str) -> Result<(), String> {
+    if input.contains(MARKER) {
+        return Err(format!(
+            "{:?} contains U+2063, which is reserved for the status marker",
+            input
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tab_name;
+
+    #[test]
+    fn test_plain_unchanged() {
+        assert_eq!(sanitize("payments-api"), "payments-api");
+        assert_eq!(sanitize("🤖 日本"), "🤖 日本");
+    }
+
+    #[test]
+    fn test_newlines_and_tabs_become_spaces() {
+        assert_eq!(sanitize("a\nb\r\nc\td"), "a b  c d");
+    }
+
+    #[test]
+    fn test_escape_sequences_stripped() {
+        assert_eq!(sanitize("\u{1b}[31mred\u{1b}[0m"), "[31mred[0m");
+        assert_eq!(sanitize("bell\u{7}"), "bell");
+        assert_eq!(sanitize("c1\u{9b}"), "c1");
+    }
+
+    #[test]
+    fn test_nfc_normalized() {
+        assert_eq!(sanitize("Cafe\u{301}"), "Caf\u{e9}");
+        assert_eq!(sanitize("Caf\u{e9}"), "Caf\u{e9}");
+        // Hangul jamo compose into a syllable
+        assert_eq!(sanitize("\u{1100}\u{1161}"), "\u{ac00}");
+    }
+
+    #[test]
+    fn test_nfc_keeps_emoji_sequences() {
+        for emoji in ["👋🏻", "👨\u{200d}👩\u{200d}👧", "⚠️", "🇺🇸"] {
+            assert_eq!(sanitize(emoji), emoji);
+        }
+    }
+
+    #[test]
+    fn test_marker_kept() {
+        // U+2063 is a format character, not a control character
+        assert_eq!(sanitize("\u{2063}🤖 Work"), "\u{2063}🤖 Work");
+    }
+
+    #[test]
+    fn test_length_limit() {
+        let long = "x".repeat(MAX_LEN + 10);
+        assert_eq!(sanitize(&long).len(), MAX_LEN);
+    }
+
+    #[test]
+    fn test_length_limit_keeps_graphemes() {
+        let long = "🇺🇸".repeat(MAX_LEN + 1);
+        assert_eq!(sanitize(&long), "🇺🇸".repeat(MAX_LEN));
+    }
+
+    #[test]
+    fn test_check_marker() {
+        assert!(check_marker("Work 🤖").is_ok());
+        assert!(check_marker("Work \u{2063}🤖").is_err());
+        assert!(check_marker("\u{2063}").is_err());
+    }
+
+    #[test]
+    fn test_marker_in_name_would_be_misparsed() {
+        // Why check_marker exists: the injected marker turns part of the name into a status
+        let name = tab_name::set_name("Work", "deploy \u{2063}🚀");
+        assert_eq!(tab_name::get_status(&name), "🚀");
+        assert_eq!(tab_name::get_name(&name), "deploy");
+    }
+}
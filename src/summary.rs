@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use crate::tab_name;
+use crate::zellij_api::{PaneEntry, TabEntry};
+
+/// One tab as reported by `--list`.
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct TabSummary {
+    pub position: usize,
+    pub tab_id: u32,
+    pub name: String,
+    pub base_name: String,
+    pub status: String,
+    pub pane_ids: Vec<u32>,
+}
+
+/// Combine list-tabs and list-panes output into per-tab summaries, ordered by position.
+/// Tabs without a reported position fall back to their order in list-tabs output.
+/// Plugin panes are left out of `pane_ids` (their IDs overlap with terminal panes).
+pub fn summarize(tabs: &[TabEntry], panes: &[PaneEntry]) -> Vec<TabSummary> {
+    let mut summaries: Vec<TabSummary> = tabs
+        .iter()
+        .enumerate()
+        .map(|(i, tab)| TabSummary {
+            position: tab.position.unwrap_or(i),
+            tab_id: tab.tab_id,
+            name: tab.name.clone(),
+            base_name: tab_name::get_name(&tab.name).to_string(),
+            status: tab_name::get_status(&tab.name).to_string(),
+            pane_ids: panes
+                .iter()
+                .filter(|p| p.tab_id == tab.tab_id && !p.is_plugin)
+                .map(|p| p.id)
+                .collect(),
+        })
+        .collect();
+    summaries.sort_by_key(|s| s.position);
+    summaries
+}
+
+/// One terminal pane as reported by `--list-panes`.
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct PaneSummary {
+    pub tab_id: u32,
+    pub tab_position: Option<usize>,
+    pub tab_name: Option<String>,
+    pub title: String,
+}
+
+/// Map every terminal pane ID to its tab. Plugin panes are left out, since
+/// their IDs overlap with terminal panes and `$ZELLIJ_PANE_ID` is always a terminal pane.
+/// Tab fields are None when the pane's tab is missing from list-tabs output.
+pub fn summarize_panes(tabs: &[TabEntry], panes: &[PaneEntry]) -> BTreeMap<u32, PaneSummary> {
+    panes
+        .iter()
+        .filter(|p| !p.is_plugin)
+        .map(|p| {
+            let tab = tabs.iter().enumerate().find(|(_, t)| t.tab_id == p.tab_id);
+            let summary = PaneSummary {
+                tab_id: p.tab_id,
+                tab_position: tab.map(|(i, t)| t.position.unwrap_or(i)),
+                tab_name: tab.map(|(_, t)| tab_name::get_name(&t.name).to_string()),
+                title: p.title.clone(),
+            };
+            (p.id, summary)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tab_name::MARKER;
+    use crate::zellij_api::fake::tab;
+
+    fn pane(id: u32, tab_id: u32, is_plugin: bool) -> PaneEntry {
+        PaneEntry {
+            id,
+            tab_id,
+            is_plugin,
+            title: format!("pane-{}", id),
+        }
+    }
+
+    #[test]
+    fn test_summarize_status_and_base_name() {
+        let tabs = vec![tab(0, &format!("{}🤖 Work", MARKER), Some(0))];
+        let result = summarize(&tabs, &[]);
+        assert_eq!(result[0].status, "🤖");
+        assert_eq!(result[0].base_name, "Work");
+        assert_eq!(result[0].name, format!("{}🤖 Work", MARKER));
+    }
+
+    #[test]
+    fn test_summarize_plain_name() {
+        let result = summarize(&[tab(3, "Plain", Some(0))], &[]);
+        assert_eq!(result[0].status, "");
+        assert_eq!(result[0].base_name, "Plain");
+        assert_eq!(result[0].tab_id, 3);
+    }
+
+    #[test]
+    fn test_summarize_groups_terminal_panes() {
+        let tabs = vec![tab(0, "A", Some(0)), tab(1, "B", Some(1))];
+        let panes = vec![
+            pane(0, 0, false),
+            pane(1, 1, false),
+            pane(2, 0, false),
+            pane(0, 1, true),
+        ];
+        let result = summarize(&tabs, &panes);
+        assert_eq!(result[0].pane_ids, vec![0, 2]);
+        assert_eq!(result[1].pane_ids, vec![1]);
+    }
+
+    #[test]
+    fn test_summarize_orders_by_position() {
+        let tabs = vec![tab(5, "Second", Some(1)), tab(2, "First", Some(0))];
+        let result = summarize(&tabs, &[]);
+        assert_eq!(result[0].name, "First");
+        assert_eq!(result[1].name, "Second");
+    }
+
+    #[test]
+    fn test_summarize_missing_position_uses_order() {
+        let tabs = vec![tab(7, "A", None), tab(9, "B", None)];
+        let result = summarize(&tabs, &[]);
+        assert_eq!(result[0].position, 0);
+        assert_eq!(result[1].position, 1);
+    }
+
+    // ==================== summarize_panes ====================
+
+    #[test]
+    fn test_summarize_panes_maps_to_tab() {
+        let tabs = vec![
+            tab(0, "A", Some(0)),
+            tab(4, &format!("{}🤖 B", MARKER), Some(1)),
+        ];
+        let panes = vec![pane(1, 0, false), pane(3, 4, false)];
+        let result = summarize_panes(&tabs, &panes);
+        let entry = &result[&3];
+        assert_eq!(entry.tab_id, 4);
+        assert_eq!(entry.tab_position, Some(1));
+        assert_eq!(entry.tab_name.as_deref(), Some("B"));
+        assert_eq!(entry.title, "pane-3");
+    }
+
+    #[test]
+    fn test_summarize_panes_skips_plugins() {
+        let tabs = vec![tab(0, "A", Some(0))];
+        let panes = vec![pane(0, 0, false), pane(0, 0, true), pane(2, 0, true)];
+        let result = summarize_panes(&tabs, &panes);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[&0].title, "pane-0");
+    }
+
+    #[test]
+    fn test_summarize_panes_unknown_tab() {
+        let result = summarize_panes(&[], &[pane(5, 9, false)]);
+        assert_eq!(result[&5].tab_id, 9);
+        assert_eq!(result[&5].tab_position, None);
+        assert_eq!(result[&5].tab_name, None);
+    }
+}
@@ -1,65 +1,246 @@
 use unicode_segmentation::UnicodeSegmentation;
 
-/// Invisible separator (U+2063) used as unambiguous marker for status-block prefix.
-/// Format: MARKER + STATUS + SPACE + base_name
+use crate::truncate::{self, TruncateMode};
+
+/// Invisible separator (U+2063) used as unambiguous marker for the status-block.
+/// Prefix format: MARKER + STATUS... + SPACE + base_name
+/// Suffix format: base_name + SPACE + MARKER + STATUS...
+///
+/// STATUS... is a stack of one or more grapheme clusters (e.g. "🔔🤖").
 pub const MARKER: char = '\u{2063}';
 
+/// Where the status-block is rendered relative to the base_name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Placement {
+    /// "🤖 Work"
+    Prefix,
+    /// "Work 🤖"
+    Suffix,
+}
+
+impl Placement {
+    /// Parse "prefix" / "suffix" (case-insensitive).
+    pub fn parse(value: &str) -> Result<Placement, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "prefix" => Ok(Placement::Prefix),
+            "suffix" => Ok(Placement::Suffix),
+            _ => Err(format!(
+                "invalid placement '{}' (expected prefix or suffix)",
+                value
+            )),
+        }
+    }
+}
+
 /// Extract the first Unicode grapheme cluster from input.
 /// Returns empty string for empty input.
 pub fn first_grapheme(input: &str) -> &str {
     input.graphemes(true).next().unwrap_or("")
 }
 
-/// Parse a tab name into (status, base_name) if it has a valid status-block.
-/// Returns None if no valid MARKER-prefixed status-block is found.
-fn parse_status_block(name: &str) -> Option<(&str, &str)> {
-    let rest = name.strip_prefix(MARKER)?;
-    let mut graphemes = rest.graphemes(true);
-    let status = graphemes.next()?;
-    let after_status = graphemes.as_str();
-    let base = after_status.strip_prefix(' ')?;
-    Some((status, base))
+/// Returns the first grapheme of input if it can be used as a status.
+/// Graphemes containing whitespace cannot, since SPACE terminates the status-block
+/// (e.g. " \u{301}" is one cluster starting with a space).
+fn status_grapheme(input: &str) -> Option<&str> {
+    let grapheme = first_grapheme(input);
+    if grapheme.is_empty() || grapheme.chars().any(char::is_whitespace) {
+        None
+    } else {
+        Some(grapheme)
+    }
+}
+
+/// Parse a tab name into (status, base_name, placement) if it has a valid status-block.
+/// The status may be a stack of several graphemes and never contains SPACE.
+/// A prefix block wins over a suffix block when both could match.
+/// Returns None if no valid MARKER-delimited status-block is found.
+fn parse_status_block(name: &str) -> Option<(&str, &str, Placement)> {
+    if let Some(rest) = name.strip_prefix(MARKER) {
+        let end = rest.find(' ')?;
+        if end == 0 {
+            return None;
+        }
+        return Some((&rest[..end], &rest[end + 1..], Placement::Prefix));
+    }
+    let separator = format!(" {}", MARKER);
+    let (base, status) = name.rsplit_once(separator.as_str())?;
+    if status.is_empty() || status.contains(' ') {
+        return None;
+    }
+    Some((status, base, Placement::Suffix))
+}
+
+/// Builds a tab name from a status stack and base_name.
+/// An empty stack yields the bare base_name.
+fn format_name(statuses: &[&str], base: &str, placement: Placement) -> String {
+    if statuses.is_empty() {
+        return base.to_string();
+    }
+    match placement {
+        Placement::Prefix => format!("{}{} {}", MARKER, statuses.concat(), base),
+        Placement::Suffix => format!("{} {}{}", base, MARKER, statuses.concat()),
+    }
+}
+
+/// Returns the placement of the existing status-block, Prefix if there is none.
+fn current_placement(current_name: &str) -> Placement {
+    get_placement(current_name).unwrap_or(Placement::Prefix)
+}
+
+/// Returns the placement of the status-block, or None if there is none.
+pub fn get_placement(current_name: &str) -> Option<Placement> {
+    parse_status_block(current_name).map(|(_, _, placement)| placement)
 }
 
 /// Returns the STATUS portion if a valid status-block is present, empty string otherwise.
+/// With stacked statuses this is the whole stack, e.g. "🔔🤖".
 pub fn get_status(current_name: &str) -> &str {
     match parse_status_block(current_name) {
-        Some((status, _)) => status,
+        Some((status, _, _)) => status,
         None => "",
     }
 }
 
+/// Returns every status in the stack, in display order.
+pub fn get_statuses(current_name: &str) -> Vec<&str> {
+    get_status(current_name).graphemes(true).collect()
+}
+
 /// Returns the base_name if a valid status-block is present, else the full name.
 pub fn get_name(current_name: &str) -> &str {
     match parse_status_block(current_name) {
-        Some((_, base)) => base,
+        Some((_, base, _)) => base,
         None => current_name,
     }
 }
 
 /// Sets or replaces the status-block. Takes first grapheme cluster from emoji.
+/// Any stacked statuses are replaced by this single one.
+/// Keeps the existing placement (Prefix for names without status).
 /// If emoji is empty, returns the name unchanged (use clear_status to remove).
 pub fn set_status(current_name: &str, emoji: &str) -> String {
-    let grapheme = first_grapheme(emoji);
-    if grapheme.is_empty() {
+    set_status_at(current_name, emoji, current_placement(current_name))
+}
+
+/// Like `set_status`, but renders the status-block at the given placement.
+pub fn set_status_at(current_name: &str, emoji: &str, placement: Placement) -> String {
+    match status_grapheme(emoji) {
+        Some(grapheme) => format_name(&[grapheme], get_name(current_name), placement),
+        None => current_name.to_string(),
+    }
+}
+
+/// Pushes a status onto the stack. Takes first grapheme cluster from emoji.
+/// Keeps the existing placement (Prefix for names without status).
+/// If the status is already in the stack, returns the name unchanged.
+pub fn add_status(current_name: &str, emoji: &str) -> String {
+    add_status_at(current_name, emoji, current_placement(current_name))
+}
+
+/// Like `add_status`, but renders the status-block at the given placement.
+/// A status already in the stack is not added twice, but the block still moves.
+pub fn add_status_at(current_name: &str, emoji: &str, placement: Placement) -> String {
+    let Some(grapheme) = status_grapheme(emoji) else {
         return current_name.to_string();
+    };
+    let mut statuses = get_statuses(current_name);
+    if !statuses.contains(&grapheme) {
+        statuses.push(grapheme);
     }
-    let base = get_name(current_name);
-    format!("{}{} {}", MARKER, grapheme, base)
+    format_name(&statuses, get_name(current_name), placement)
+}
+
+/// Removes a single status from the stack. Takes first grapheme cluster from emoji.
+/// Removing the last status drops the status-block entirely.
+pub fn remove_status(current_name: &str, emoji: &str) -> String {
+    let Some(grapheme) = status_grapheme(emoji) else {
+        return current_name.to_string();
+    };
+    let statuses = get_statuses(current_name);
+    if !statuses.contains(&grapheme) {
+        return current_name.to_string();
+    }
+    let remaining: Vec<&str> = statuses.into_iter().filter(|s| *s != grapheme).collect();
+    format_name(
+        &remaining,
+        get_name(current_name),
+        current_placement(current_name),
+    )
 }
 
-/// Removes the status-block if present, returning the base_name.
+/// Removes the status if it is in the stack, otherwise sets it (replacing the stack).
+/// Takes first grapheme cluster from emoji. Keeps the existing placement.
+pub fn toggle_status(current_name: &str, emoji: &str) -> String {
+    toggle_status_at(current_name, emoji, current_placement(current_name))
+}
+
+/// Like `toggle_status`, but a newly set status is rendered at the given placement.
+pub fn toggle_status_at(current_name: &str, emoji: &str, placement: Placement) -> String {
+    match status_grapheme(emoji) {
+        Some(grapheme) if get_statuses(current_name).contains(&grapheme) => {
+            remove_status(current_name, grapheme)
+        }
+        _ => set_status_at(current_name, emoji, placement),
+    }
+}
+
+/// Advances the status to the next entry of `cycle`, e.g. none → 🔴 → 🟡 → 🟢 → none.
+/// A status that is not in `cycle` (including none) moves to the first entry;
+/// the last entry moves back to no status. Keeps the existing placement.
+pub fn cycle_status(current_name: &str, cycle: &[String]) -> String {
+    cycle_status_at(current_name, cycle, current_placement(current_name))
+}
+
+/// Like `cycle_status`, but a newly set status is rendered at the given placement.
+pub fn cycle_status_at(current_name: &str, cycle: &[String], placement: Placement) -> String {
+    let current = get_status(current_name);
+    let next = match cycle.iter().position(|s| first_grapheme(s) == current) {
+        Some(i) if !current.is_empty() => cycle.get(i + 1),
+        _ => cycle.first(),
+    };
+    match next {
+        Some(emoji) => set_status_at(current_name, emoji, placement),
+        None => clear_status(current_name),
+    }
+}
+
+/// Removes the whole status-block (all stacked statuses) if present, returning the base_name.
 /// If no status-block, returns the name unchanged.
 pub fn clear_status(current_name: &str) -> String {
     get_name(current_name).to_string()
 }
 
-/// Preserves existing status-block (if any) and replaces the base_name.
+/// Preserves existing status-block (if any, at its placement) and replaces the base_name.
 pub fn set_name(current_name: &str, new_name: &str) -> String {
-    match parse_status_block(current_name) {
-        Some((status, _)) => format!("{}{} {}", MARKER, status, new_name),
-        None => new_name.to_string(),
+    let statuses = get_statuses(current_name);
+    format_name(&statuses, new_name, current_placement(current_name))
+}
+
+/// Truncates the base_name so the whole visible name (status-block included)
+/// fits in `max_width` terminal columns. The status-block itself is never cut,
+/// and the base_name keeps at least one grapheme, so a tiny width may be exceeded.
+pub fn fit_width(current_name: &str, max_width: usize, mode: TruncateMode) -> String {
+    let status = get_status(current_name);
+    let reserved = if status.is_empty() {
+        0
+    } else {
+        truncate::display_width(status) + 1
+    };
+    let base = get_name(current_name);
+    let mut fitted = truncate::truncate(base, max_width.saturating_sub(reserved), mode);
+    if fitted.is_empty() || fitted == truncate::ELLIPSIS {
+        let first = first_grapheme(base);
+        fitted = if first == base {
+            base.to_string()
+        } else {
+            format!("{}{}", first, truncate::ELLIPSIS)
+        };
+    }
+    if fitted == base {
+        return current_name.to_string();
     }
+    set_name(current_name, &fitted)
 }
 
 #[cfg(test)]
@@ -339,6 +520,34 @@ mod tests {
         assert_eq!(get_name(&result), "");
     }
 
+    #[test]
+    fn test_set_status_after_set_name() {
+        let renamed = set_name("Old", "New");
+        let result = set_status(&renamed, "🤖");
+        assert_eq!(result, format!("{}🤖 New", MARKER));
+    }
+
+    #[test]
+    fn test_set_name_then_clear_keeps_new_name() {
+        let with_status = set_status("Old", "🤖");
+        let renamed = set_name(&with_status, "New");
+        assert_eq!(clear_status(&renamed), "New");
+    }
+
+    #[test]
+    fn test_set_name_with_space_in_new_name() {
+        let with_status = set_status("Old", "🤖");
+        let renamed = set_name(&with_status, "two words");
+        assert_eq!(get_status(&renamed), "🤖");
+        assert_eq!(get_name(&renamed), "two words");
+    }
+
+    #[test]
+    fn test_set_name_idempotent() {
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(set_name(&name, "Work"), name);
+    }
+
     // ==================== Malformed marker prefix ====================
 
     #[test]
@@ -366,6 +575,356 @@ mod tests {
         assert_eq!(get_name(&name), &name);
     }
 
+    #[test]
+    fn test_set_status_whitespace_noop() {
+        assert_eq!(set_status("Tab", " "), "Tab");
+        assert_eq!(set_status("Tab", "\t"), "Tab");
+    }
+
+    #[test]
+    fn test_status_grapheme_with_leading_space_rejected() {
+        // Space + combining mark is a single grapheme cluster
+        assert_eq!(set_status("Work", " \u{301}"), "Work");
+        assert_eq!(add_status("Work", " \u{301}"), "Work");
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(add_status(&name, " \u{301}"), name);
+        assert_eq!(toggle_status(&name, " \u{301}"), name);
+    }
+
+    // ==================== Stacked statuses ====================
+
+    #[test]
+    fn test_get_status_stacked() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(get_status(&name), "🔔🤖");
+        assert_eq!(get_name(&name), "Work");
+    }
+
+    #[test]
+    fn test_get_statuses_stacked() {
+        let name = format!("{}🔔🇺🇸👋🏻 Work", MARKER);
+        assert_eq!(get_statuses(&name), vec!["🔔", "🇺🇸", "👋🏻"]);
+    }
+
+    #[test]
+    fn test_get_statuses_none() {
+        assert!(get_statuses("Work").is_empty());
+    }
+
+    #[test]
+    fn test_add_status_plain_name() {
+        assert_eq!(add_status("Work", "🔔"), format!("{}🔔 Work", MARKER));
+    }
+
+    #[test]
+    fn test_add_status_stacks() {
+        let result = add_status(&add_status("Work", "🔔"), "🤖");
+        assert_eq!(result, format!("{}🔔🤖 Work", MARKER));
+    }
+
+    #[test]
+    fn test_add_status_duplicate_noop() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(add_status(&name, "🤖"), name);
+    }
+
+    #[test]
+    fn test_add_status_empty_noop() {
+        assert_eq!(add_status("Work", ""), "Work");
+    }
+
+    #[test]
+    fn test_remove_status_from_stack() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(remove_status(&name, "🔔"), format!("{}🤖 Work", MARKER));
+    }
+
+    #[test]
+    fn test_remove_status_last_drops_block() {
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(remove_status(&name, "🤖"), "Work");
+    }
+
+    #[test]
+    fn test_remove_status_absent_noop() {
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(remove_status(&name, "🔔"), name);
+        assert_eq!(remove_status("Work", "🔔"), "Work");
+    }
+
+    #[test]
+    fn test_set_status_replaces_stack() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(set_status(&name, "✅"), format!("{}✅ Work", MARKER));
+    }
+
+    #[test]
+    fn test_clear_status_removes_stack() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(clear_status(&name), "Work");
+    }
+
+    #[test]
+    fn test_set_name_preserves_stack() {
+        let name = format!("{}🔔🤖 Work", MARKER);
+        assert_eq!(set_name(&name, "Play"), format!("{}🔔🤖 Play", MARKER));
+    }
+
+    // ==================== Suffix placement ====================
+
+    #[test]
+    fn test_placement_parse() {
+        assert_eq!(Placement::parse("prefix"), Ok(Placement::Prefix));
+        assert_eq!(Placement::parse("SUFFIX"), Ok(Placement::Suffix));
+        assert!(Placement::parse("middle").is_err());
+    }
+
+    #[test]
+    fn test_get_status_suffix() {
+        let name = format!("Work {}🤖", MARKER);
+        assert_eq!(get_status(&name), "🤖");
+        assert_eq!(get_name(&name), "Work");
+        assert_eq!(get_placement(&name), Some(Placement::Suffix));
+    }
+
+    #[test]
+    fn test_get_status_suffix_stacked() {
+        let name = format!("My Work {}🔔🤖", MARKER);
+        assert_eq!(get_statuses(&name), vec!["🔔", "🤖"]);
+        assert_eq!(get_name(&name), "My Work");
+    }
+
+    #[test]
+    fn test_get_placement_none() {
+        assert_eq!(get_placement("Work"), None);
+        assert_eq!(
+            get_placement(&format!("{}🤖 Work", MARKER)),
+            Some(Placement::Prefix)
+        );
+    }
+
+    #[test]
+    fn test_suffix_malformed_no_status() {
+        let name = format!("Work {}", MARKER);
+        assert_eq!(get_status(&name), "");
+        assert_eq!(get_name(&name), &name);
+    }
+
+    #[test]
+    fn test_suffix_malformed_space_in_status() {
+        let name = format!("Work {}🤖 more", MARKER);
+        assert_eq!(get_status(&name), "");
+        assert_eq!(get_name(&name), &name);
+    }
+
+    #[test]
+    fn test_set_status_at_suffix() {
+        let result = set_status_at("Work", "🤖", Placement::Suffix);
+        assert_eq!(result, format!("Work {}🤖", MARKER));
+    }
+
+    #[test]
+    fn test_set_status_at_moves_block() {
+        let name = format!("{}🤖 Work", MARKER);
+        let result = set_status_at(&name, "✅", Placement::Suffix);
+        assert_eq!(result, format!("Work {}✅", MARKER));
+        let back = set_status_at(&result, "✅", Placement::Prefix);
+        assert_eq!(back, format!("{}✅ Work", MARKER));
+    }
+
+    #[test]
+    fn test_set_status_keeps_suffix() {
+        let name = format!("Work {}🤖", MARKER);
+        assert_eq!(set_status(&name, "✅"), format!("Work {}✅", MARKER));
+    }
+
+    #[test]
+    fn test_add_status_keeps_suffix() {
+        let name = format!("Work {}🔔", MARKER);
+        assert_eq!(add_status(&name, "🤖"), format!("Work {}🔔🤖", MARKER));
+    }
+
+    #[test]
+    fn test_add_status_at_existing_moves_block() {
+        let name = format!("{}🤖 Work", MARKER);
+        let result = add_status_at(&name, "🤖", Placement::Suffix);
+        assert_eq!(result, format!("Work {}🤖", MARKER));
+    }
+
+    #[test]
+    fn test_remove_status_suffix() {
+        let name = format!("Work {}🔔🤖", MARKER);
+        assert_eq!(remove_status(&name, "🔔"), format!("Work {}🤖", MARKER));
+        assert_eq!(remove_status(&format!("Work {}🤖", MARKER), "🤖"), "Work");
+    }
+
+    #[test]
+    fn test_clear_status_suffix() {
+        assert_eq!(clear_status(&format!("Work {}🤖", MARKER)), "Work");
+    }
+
+    #[test]
+    fn test_set_name_keeps_suffix() {
+        let name = format!("Work {}🤖", MARKER);
+        assert_eq!(set_name(&name, "Play"), format!("Play {}🤖", MARKER));
+    }
+
+    #[test]
+    fn test_suffix_base_with_spaces() {
+        let result = set_status_at("  spaced  name ", "🤖", Placement::Suffix);
+        assert_eq!(get_name(&result), "  spaced  name ");
+        assert_eq!(get_status(&result), "🤖");
+    }
+
+    // ==================== toggle_status ====================
+
+    #[test]
+    fn test_toggle_sets_when_absent() {
+        assert_eq!(toggle_status("Work", "🔖"), format!("{}🔖 Work", MARKER));
+    }
+
+    #[test]
+    fn test_toggle_clears_when_present() {
+        assert_eq!(toggle_status(&format!("{}🔖 Work", MARKER), "🔖"), "Work");
+    }
+
+    #[test]
+    fn test_toggle_replaces_other_status() {
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(toggle_status(&name, "🔖"), format!("{}🔖 Work", MARKER));
+    }
+
+    #[test]
+    fn test_toggle_removes_from_stack() {
+        let name = format!("{}🔔🔖 Work", MARKER);
+        assert_eq!(toggle_status(&name, "🔖"), format!("{}🔔 Work", MARKER));
+    }
+
+    #[test]
+    fn test_toggle_keeps_suffix() {
+        let name = format!("Work {}🔖", MARKER);
+        assert_eq!(toggle_status(&name, "🔖"), "Work");
+        assert_eq!(
+            toggle_status_at("Work", "🔖", Placement::Suffix),
+            format!("Work {}🔖", MARKER)
+        );
+    }
+
+    #[test]
+    fn test_toggle_round_trip() {
+        let once = toggle_status("Work", "🔖");
+        assert_eq!(toggle_status(&once, "🔖"), "Work");
+    }
+
+    // ==================== cycle_status ====================
+
+    fn cycle(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_cycle_full_round() {
+        let order = cycle(&["🔴", "🟡", "🟢"]);
+        let first = cycle_status("Work", &order);
+        assert_eq!(get_status(&first), "🔴");
+        let second = cycle_status(&first, &order);
+        assert_eq!(get_status(&second), "🟡");
+        let third = cycle_status(&second, &order);
+        assert_eq!(get_status(&third), "🟢");
+        assert_eq!(cycle_status(&third, &order), "Work");
+    }
+
+    #[test]
+    fn test_cycle_unknown_status_starts_over() {
+        let name = format!("{}🤖 Work", MARKER);
+        assert_eq!(
+            cycle_status(&name, &cycle(&["🔴", "🟡"])),
+            format!("{}🔴 Work", MARKER)
+        );
+    }
+
+    #[test]
+    fn test_cycle_replaces_stack() {
+        let name = format!("{}🔴🔔 Work", MARKER);
+        assert_eq!(
+            cycle_status(&name, &cycle(&["🔴", "🟡"])),
+            format!("{}🔴 Work", MARKER)
+        );
+    }
+
+    #[test]
+    fn test_cycle_keeps_suffix() {
+        let name = format!("Work {}🔴", MARKER);
+        assert_eq!(
+            cycle_status(&name, &cycle(&["🔴", "🟡"])),
+            format!("Work {}🟡", MARKER)
+        );
+    }
+
+    #[test]
+    fn test_cycle_empty_list_clears() {
+        let name = format!("{}🔴 Work", MARKER);
+        assert_eq!(cycle_status(&name, &[]), "Work");
+    }
+
+    // ==================== fit_width ====================
+
+    #[test]
+    fn test_fit_width_reserves_status() {
+        // "🤖 " takes 3 columns, leaving 7 for the base
+        let name = format!("{}🤖 payments-api", MARKER);
+        let result = fit_width(&name, 10, TruncateMode::End);
+        assert_eq!(result, format!("{}🤖 paymen…", MARKER));
+    }
+
+    #[test]
+    fn test_fit_width_no_status() {
+        assert_eq!(fit_width("payments-api", 8, TruncateMode::End), "payment…");
+    }
+
+    #[test]
+    fn test_fit_width_fits_unchanged() {
+        let name = format!("{}🤖 api", MARKER);
+        assert_eq!(fit_width(&name, 20, TruncateMode::End), name);
+    }
+
+    #[test]
+    fn test_fit_width_suffix() {
+        let name = format!("payments-api {}🔔🤖", MARKER);
+        let result = fit_width(&name, 10, TruncateMode::Start);
+        // "🔔🤖" + SPACE take 5 columns, leaving 5 for the base
+        assert_eq!(result, format!("…-api {}🔔🤖", MARKER));
+    }
+
+    #[test]
+    fn test_fit_width_emoji_presentation() {
+        // "🟡 " and "⚡ " take 3 columns each, leaving 7 for the base
+        for emoji in ["🟡", "⚡"] {
+            let name = format!("{}{} payments-api", MARKER, emoji);
+            let result = fit_width(&name, 10, TruncateMode::End);
+            assert_eq!(result, format!("{}{} paymen…", MARKER, emoji));
+            assert_eq!(truncate::display_width(&result), 10);
+        }
+    }
+
+    #[test]
+    fn test_fit_width_never_erases_base() {
+        assert_eq!(fit_width("payments-api", 0, TruncateMode::End), "p…");
+        assert_eq!(fit_width("payments-api", 1, TruncateMode::Start), "p…");
+        assert_eq!(fit_width("x", 0, TruncateMode::End), "x");
+        let name = format!("{}🤖 payments-api", MARKER);
+        assert_eq!(
+            fit_width(&name, 3, TruncateMode::Middle),
+            format!("{}🤖 p…", MARKER)
+        );
+    }
+
+    #[test]
+    fn test_fit_width_empty_base() {
+        let name = format!("{}🤖 ", MARKER);
+        assert_eq!(fit_width(&name, 0, TruncateMode::End), name);
+    }
+
     // ==================== Round-trip consistency ====================
 
     #[test]
@@ -385,4 +944,135 @@ mod tests {
         assert_eq!(get_name(&renamed), "Tab2");
         assert_eq!(get_status(&renamed), "🤖");
     }
+
+    // ==================== Generated inputs ====================
+    // Deterministic pseudo-random names built from tricky graphemes (flags, skin tones,
+    // ZWJ sequences, combining marks, RTL text, spaces), checked against invariants.
+
+    const BASE_GRAPHEMES: &[&str] = &[
+        "a",
+        "Z",
+        "0",
+        "-",
+        " ",
+        "🤖",
+        "🇺🇸",
+        "👋🏻",
+        "👨‍👩‍👧",
+        "⚠️",
+        "e\u{301}",
+        "日",
+        "ש",
+        "ع",
+        "\u{200F}",
+    ];
+    const STATUS_GRAPHEMES: &[&str] = &["🤖", "🇺🇸", "👋🏻", "👨‍👩‍👧", "⚠️", "✅", "x", "日", "e\u{301}"];
+
+    /// xorshift64: tiny deterministic generator, no extra dependency needed.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 as usize
+        }
+
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[self.next() % items.len()]
+        }
+
+        fn string(&mut self, items: &[&str], max_len: usize) -> String {
+            let len = self.next() % (max_len + 1);
+            (0..len).map(|_| self.pick(items)).collect()
+        }
+    }
+
+    const CASES: usize = 500;
+
+    #[test]
+    fn test_generated_set_get_round_trip() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..CASES {
+            let base = rng.string(BASE_GRAPHEMES, 8);
+            let status = rng.pick(STATUS_GRAPHEMES);
+            for placement in [Placement::Prefix, Placement::Suffix] {
+                let name = set_status_at(&base, status, placement);
+                assert_eq!(get_status(&name), status, "{:?} {:?}", base, placement);
+                assert_eq!(get_name(&name), base, "{:?} {:?}", base, placement);
+                assert_eq!(clear_status(&name), base, "{:?} {:?}", base, placement);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_clear_idempotent() {
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+        for _ in 0..CASES {
+            let base = rng.string(BASE_GRAPHEMES, 8);
+            let name = add_status(&set_status(&base, rng.pick(STATUS_GRAPHEMES)), "🔔");
+            let once = clear_status(&name);
+            assert_eq!(clear_status(&once), once, "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn test_generated_add_remove_restores() {
+        let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+        for _ in 0..CASES {
+            let base = rng.string(BASE_GRAPHEMES, 8);
+            let (first, second) = (rng.pick(STATUS_GRAPHEMES), rng.pick(STATUS_GRAPHEMES));
+            let one = add_status(&base, first);
+            let two = add_status(&one, second);
+            if first != second {
+                assert_eq!(get_statuses(&two), vec![first, second], "{:?}", base);
+            }
+            assert_eq!(
+                remove_status(&two, second),
+                if first == second { base.clone() } else { one },
+                "{:?}",
+                base
+            );
+            assert_eq!(
+                remove_status(&remove_status(&two, first), second),
+                base,
+                "{:?}",
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_set_name_keeps_statuses() {
+        let mut rng = Rng(0x94D0_49BB_1331_11EB);
+        for _ in 0..CASES {
+            let base = rng.string(BASE_GRAPHEMES, 8);
+            let new_base = rng.string(BASE_GRAPHEMES, 8);
+            let name = add_status(&set_status(&base, rng.pick(STATUS_GRAPHEMES)), "🔔");
+            let renamed = set_name(&name, &new_base);
+            assert_eq!(get_statuses(&renamed), get_statuses(&name), "{:?}", name);
+            assert_eq!(get_name(&renamed), new_base, "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn test_generated_arbitrary_names_do_not_panic() {
+        // Names set outside this tool may contain stray markers anywhere
+        let mut pool = BASE_GRAPHEMES.to_vec();
+        pool.extend(["\u{2063}", "\u{2063}🤖", "\u{200D}", "\u{FE0F}"]);
+        let mut rng = Rng(0xBF58_476D_1CE4_E5B9);
+        for _ in 0..CASES {
+            let name = rng.string(&pool, 10);
+            let status = rng.pick(STATUS_GRAPHEMES);
+            let _ = (get_status(&name), get_statuses(&name), get_name(&name));
+            let _ = (get_placement(&name), clear_status(&name));
+            let _ = (set_status(&name, status), add_status(&name, status));
+            let _ = (remove_status(&name, status), toggle_status(&name, status));
+            let _ = set_name(&name, "x");
+            for mode in [TruncateMode::Start, TruncateMode::Middle, TruncateMode::End] {
+                let _ = fit_width(&name, rng.next() % 12, mode);
+            }
+        }
+    }
 }
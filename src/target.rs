@@ -0,0 +1,210 @@
+use crate::tab_name;
+use crate::zellij_api::TabEntry;
+
+/// How the user selected the tab to operate on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    /// `--pane-id` or `$ZELLIJ_PANE_ID`: resolved via list-panes
+    Pane(u32),
+    /// `--tab-id`: used directly
+    TabId(u32),
+    /// `--tab-name`: base name (status stripped), must match exactly one tab
+    TabName(String),
+    /// `--tab-position`: 0-based position in the tab bar
+    TabPosition(usize),
+    /// `--focused`: the active tab of the session
+    Focused,
+}
+
+/// Find the tab whose base name equals `name`.
+/// Errors if no tab or more than one tab matches.
+pub fn find_by_name<'a>(tabs: &'a [TabEntry], name: &str) -> Result<&'a TabEntry, String> {
+    let matches: Vec<&TabEntry> = tabs
+        .iter()
+        .filter(|t| tab_name::get_name(&t.name) == name)
+        .collect();
+    match matches.as_slice() {
+        [tab] => Ok(tab),
+        [] => Err(format!("No tab named '{}'", name)),
+        _ => Err(format!(
+            "Tab name '{}' is ambiguous ({} tabs match), use --tab-id instead",
+            name,
+            matches.len()
+        )),
+    }
+}
+
+/// Find the tab at 0-based `position`.
+/// Tabs without a reported position fall back to their order in list-tabs output.
+pub fn find_by_position(tabs: &[TabEntry], position: usize) -> Result<&TabEntry, String> {
+    tabs.iter()
+        .enumerate()
+        .find(|(i, t)| t.position.unwrap_or(*i) == position)
+        .map(|(_, t)| t)
+        .ok_or_else(|| format!("No tab at position {}", position))
+}
+
+/// Find the active (focused) tab.
+pub fn find_focused(tabs: &[TabEntry]) -> Result<&TabEntry, String> {
+    tabs.iter()
+        .find(|t| t.active)
+        .ok_or_else(|| "No active tab reported by list-tabs".to_string())
+}
+
+/// Match `text` against a glob `pattern`: `*` matches any run of characters,
+/// `?` exactly one character, everything else matches itself (case-sensitive).
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position after the last '*' and the text position it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p + 1, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Give tabs without a reported position their order in list-tabs output,
+/// so every `TabEntry` handed out has one.
+pub fn fill_positions(tabs: &mut [TabEntry]) {
+    for (i, tab) in tabs.iter_mut().enumerate() {
+        tab.position.get_or_insert(i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tab_name::MARKER;
+    use crate::zellij_api::fake::tab;
+
+    // ==================== find_by_name ====================
+
+    #[test]
+    fn test_find_by_name_plain() {
+        let tabs = vec![tab(0, "Alpha", Some(0)), tab(4, "Beta", Some(1))];
+        assert_eq!(find_by_name(&tabs, "Beta").map(|t| t.tab_id), Ok(4));
+    }
+
+    #[test]
+    fn test_find_by_name_ignores_status() {
+        let tabs = vec![tab(3, &format!("{}🤖 Build", MARKER), Some(0))];
+        assert_eq!(find_by_name(&tabs, "Build").map(|t| t.tab_id), Ok(3));
+    }
+
+    #[test]
+    fn test_find_by_name_not_found() {
+        let tabs = vec![tab(0, "Alpha", Some(0))];
+        assert!(find_by_name(&tabs, "Gamma").is_err());
+    }
+
+    #[test]
+    fn test_find_by_name_ambiguous() {
+        let tabs = vec![
+            tab(0, "Dup", Some(0)),
+            tab(1, &format!("{}✅ Dup", MARKER), Some(1)),
+        ];
+        let err = find_by_name(&tabs, "Dup").unwrap_err();
+        assert!(err.contains("ambiguous"));
+    }
+
+    // ==================== find_by_position ====================
+
+    #[test]
+    fn test_find_by_position() {
+        let tabs = vec![tab(7, "B", Some(1)), tab(2, "A", Some(0))];
+        assert_eq!(find_by_position(&tabs, 0).map(|t| t.tab_id), Ok(2));
+        assert_eq!(find_by_position(&tabs, 1).map(|t| t.tab_id), Ok(7));
+    }
+
+    #[test]
+    fn test_find_by_position_fallback_to_order() {
+        let tabs = vec![tab(7, "A", None), tab(9, "B", None)];
+        assert_eq!(find_by_position(&tabs, 1).map(|t| t.tab_id), Ok(9));
+    }
+
+    #[test]
+    fn test_find_by_position_out_of_range() {
+        let tabs = vec![tab(0, "A", Some(0))];
+        assert!(find_by_position(&tabs, 5).is_err());
+    }
+
+    // ==================== find_focused ====================
+
+    #[test]
+    fn test_find_focused() {
+        let mut tabs = vec![tab(0, "A", Some(0)), tab(4, "B", Some(1))];
+        tabs[1].active = true;
+        assert_eq!(find_focused(&tabs).map(|t| t.tab_id), Ok(4));
+    }
+
+    #[test]
+    fn test_find_focused_none_active() {
+        assert!(find_focused(&[tab(0, "A", Some(0))]).is_err());
+    }
+
+    // ==================== glob_match ====================
+
+    #[test]
+    fn test_glob_literal() {
+        assert!(glob_match("payments", "payments"));
+        assert!(!glob_match("payments", "payments-api"));
+    }
+
+    #[test]
+    fn test_glob_star() {
+        assert!(glob_match("payments-*", "payments-api"));
+        assert!(glob_match("payments-*", "payments-"));
+        assert!(glob_match("*-api", "payments-api"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("p*s*i", "payments-api"));
+        assert!(!glob_match("payments-*", "billing-api"));
+    }
+
+    #[test]
+    fn test_glob_question_mark() {
+        assert!(glob_match("tab-?", "tab-1"));
+        assert!(!glob_match("tab-?", "tab-10"));
+    }
+
+    #[test]
+    fn test_glob_unicode() {
+        assert!(glob_match("日本-?", "日本-語"));
+    }
+
+    // ==================== fill_positions ====================
+
+    #[test]
+    fn test_fill_positions_keeps_reported() {
+        let mut tabs = vec![tab(7, "B", Some(1)), tab(2, "A", Some(0))];
+        fill_positions(&mut tabs);
+        assert_eq!(tabs[0].position, Some(1));
+        assert_eq!(tabs[1].position, Some(0));
+    }
+
+    #[test]
+    fn test_fill_positions_fallback_to_order() {
+        let mut tabs = vec![tab(7, "A", None), tab(9, "B", None)];
+        fill_positions(&mut tabs);
+        assert_eq!(tabs[0].position, Some(0));
+        assert_eq!(tabs[1].position, Some(1));
+    }
+}
@@ -0,0 +1,197 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Appended (or prepended) in place of the removed part of a name.
+pub const ELLIPSIS: &str = "…";
+
+/// Which part of a too-long name is replaced by the ellipsis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TruncateMode {
+    /// "…-service-api"
+    Start,
+    /// "payme…ce-api"
+    Middle,
+    /// "payments-serv…"
+    End,
+}
+
+impl TruncateMode {
+    /// Parse "start" / "middle" / "end" (case-insensitive).
+    pub fn parse(value: &str) -> Result<TruncateMode, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "start" => Ok(TruncateMode::Start),
+            "middle" => Ok(TruncateMode::Middle),
+            "end" => Ok(TruncateMode::End),
+            _ => Err(format!(
+                "invalid truncate mode '{}' (expected start, middle or end)",
+                value
+            )),
+        }
+    }
+}
+
+/// Terminal columns taken by a single grapheme cluster, per `unicode-width`
+/// (East Asian Width plus emoji presentation). A cluster never takes more than
+/// two columns, e.g. "👨‍👩‍👧" or "🇺🇸".
+fn grapheme_width(grapheme: &str) -> usize {
+    grapheme.width().min(2)
+}
+
+/// Terminal display width of `input`.
+pub fn display_width(input: &str) -> usize {
+    input.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Take graphemes from the front of `input` while they fit in `budget` columns.
+fn take_front(input: &str, budget: usize) -> &str {
+    let mut width = 0;
+    let mut end = 0;
+    for (idx, grapheme) in input.grapheme_indices(true) {
+        width += grapheme_width(grapheme);
+        if width > budget {
+            break;
+        }
+        end = idx + grapheme.len();
+    }
+    &input[..end]
+}
+
+/// Take graphemes from the back of `input` while they fit in `budget` columns.
+fn take_back(input: &str, budget: usize) -> &str {
+    let mut width = 0;
+    let mut start = input.len();
+    for (idx, grapheme) in input.grapheme_indices(true).rev() {
+        width += grapheme_width(grapheme);
+        if width > budget {
+            break;
+        }
+        start = idx;
+    }
+    &input[start..]
+}
+
+/// Shorten `input` to at most `max_width` columns, replacing the cut part with ELLIPSIS.
+/// Never splits a grapheme cluster. Names that already fit are returned unchanged.
+pub fn truncate(input: &str, max_width: usize, mode: TruncateMode) -> String {
+    if display_width(input) <= max_width {
+        return input.to_string();
+    }
+    let budget = match max_width.checked_sub(display_width(ELLIPSIS)) {
+        Some(budget) => budget,
+        None => return String::new(),
+    };
+    match mode {
+        TruncateMode::End => format!("{}{}", take_front(input, budget), ELLIPSIS),
+        TruncateMode::Start => format!("{}{}", ELLIPSIS, take_back(input, budget)),
+        TruncateMode::Middle => {
+            let back_budget = budget / 2;
+            let front = take_front(input, budget - back_budget);
+            let back = take_back(input, back_budget);
+            format!("{}{}{}", front, ELLIPSIS, back)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== display_width ====================
+
+    #[test]
+    fn test_width_ascii() {
+        assert_eq!(display_width("hello"), 5);
+        assert_eq!(display_width(""), 0);
+    }
+
+    #[test]
+    fn test_width_emoji() {
+        assert_eq!(display_width("🤖"), 2);
+        assert_eq!(display_width("🇺🇸"), 2);
+        assert_eq!(display_width("👋🏻"), 2);
+        assert_eq!(display_width("👨‍👩‍👧"), 2);
+        assert_eq!(display_width("⚠️"), 2);
+    }
+
+    #[test]
+    fn test_width_emoji_presentation() {
+        // Regression: these were missing from a hand-written table and counted as 1
+        for emoji in ["🟡", "🟢", "🔴", "⚡", "⭐", "✨", "⛔", "⭕", "⚓"] {
+            assert_eq!(display_width(emoji), 2, "{}", emoji);
+        }
+    }
+
+    #[test]
+    fn test_width_cjk() {
+        assert_eq!(display_width("日本"), 4);
+    }
+
+    #[test]
+    fn test_width_marker_is_zero() {
+        assert_eq!(display_width("\u{2063}"), 0);
+    }
+
+    #[test]
+    fn test_width_combining() {
+        // "e" + combining acute accent
+        assert_eq!(display_width("e\u{0301}"), 1);
+    }
+
+    // ==================== truncate ====================
+
+    #[test]
+    fn test_truncate_fits_unchanged() {
+        assert_eq!(truncate("short", 10, TruncateMode::End), "short");
+        assert_eq!(truncate("exact", 5, TruncateMode::End), "exact");
+    }
+
+    #[test]
+    fn test_truncate_end() {
+        assert_eq!(truncate("payments-api", 8, TruncateMode::End), "payment…");
+    }
+
+    #[test]
+    fn test_truncate_start() {
+        assert_eq!(truncate("payments-api", 8, TruncateMode::Start), "…nts-api");
+    }
+
+    #[test]
+    fn test_truncate_middle() {
+        assert_eq!(
+            truncate("payments-api", 8, TruncateMode::Middle),
+            "paym…api"
+        );
+    }
+
+    #[test]
+    fn test_truncate_wide_graphemes_not_split() {
+        // Each CJK char is 2 columns: budget 4 fits 2 chars
+        assert_eq!(truncate("日本語テキスト", 5, TruncateMode::End), "日本…");
+        assert_eq!(truncate("🤖🤖🤖🤖", 4, TruncateMode::End), "🤖…");
+    }
+
+    #[test]
+    fn test_truncate_result_within_width() {
+        for mode in [TruncateMode::Start, TruncateMode::Middle, TruncateMode::End] {
+            let result = truncate("a-very-long-🇺🇸-name", 7, mode);
+            assert!(display_width(&result) <= 7, "{:?}: {}", mode, result);
+        }
+    }
+
+    #[test]
+    fn test_truncate_zero_width() {
+        assert_eq!(truncate("abc", 0, TruncateMode::End), "");
+    }
+
+    #[test]
+    fn test_truncate_width_one_is_ellipsis() {
+        assert_eq!(truncate("abc", 1, TruncateMode::End), "…");
+    }
+
+    #[test]
+    fn test_truncate_mode_parse() {
+        assert_eq!(TruncateMode::parse("Middle"), Ok(TruncateMode::Middle));
+        assert!(TruncateMode::parse("left").is_err());
+    }
+}
@@ -1,94 +1,262 @@
 use std::process::Command;
 
+use crate::target;
+
 /// Returns the zellij binary path: `$ZELLIJ_PATH` if set, otherwise `"zellij"`.
 fn zellij_bin() -> String {
     std::env::var("ZELLIJ_PATH").unwrap_or_else(|_| "zellij".to_string())
 }
 
-#[derive(serde::Deserialize)]
-struct PaneEntry {
-    id: u32,
-    tab_id: u32,
+/// Pane entry from `zellij action list-panes --json`
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PaneEntry {
+    pub id: u32,
+    pub tab_id: u32,
+    #[serde(default)]
+    pub is_plugin: bool,
     #[serde(default)]
-    is_plugin: bool,
+    pub title: String,
 }
 
-#[derive(serde::Deserialize)]
-struct TabEntry {
-    tab_id: u32,
-    name: String,
+/// Tab entry from `zellij action list-tabs --json`
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TabEntry {
+    pub tab_id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub position: Option<usize>,
+    /// Whether this is the focused tab
+    #[serde(default)]
+    pub active: bool,
 }
 
-/// Resolve pane_id to tab_id via `zellij action list-panes --json`
-pub fn resolve_tab_id(pane_id: u32) -> Result<u32, String> {
-    let bin = zellij_bin();
-    let output = Command::new(&bin)
-        .args(["action", "list-panes", "--json"])
-        .output()
-        .map_err(|e| format!("Failed to run 'zellij action list-panes --json': {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!(
-            "'zellij action list-panes --json' failed (exit {}): {}",
-            output.status, stderr
-        ));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let panes: Vec<PaneEntry> =
-        serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse panes JSON: {}", e))?;
-
-    // Prefer non-plugin panes (plugin pane IDs can overlap with terminal pane IDs)
-    panes
-        .iter()
-        .find(|p| p.id == pane_id && !p.is_plugin)
-        .or_else(|| panes.iter().find(|p| p.id == pane_id))
-        .map(|p| p.tab_id)
-        .ok_or_else(|| format!("Pane ID {} not found in list-panes output", pane_id))
+/// The Zellij operations this tool relies on.
+/// `Cli` talks to a real session; tests substitute an in-memory fake.
+pub trait ZellijApi {
+    /// List all panes (`zellij action list-panes --json`)
+    fn list_panes(&self) -> Result<Vec<PaneEntry>, String>;
+
+    /// List all tabs (`zellij action list-tabs --json`)
+    fn list_tabs(&self) -> Result<Vec<TabEntry>, String>;
+
+    /// Rename tab by id (`zellij action rename-tab-by-id <id> <name>`)
+    fn rename_tab(&self, tab_id: u32, new_name: &str) -> Result<(), String>;
+
+    /// Focus the tab at 0-based `position` (`zellij action go-to-tab <position + 1>`)
+    fn go_to_tab(&self, position: usize) -> Result<(), String>;
+
+    /// Resolve pane_id to tab_id via list_panes
+    fn resolve_tab_id(&self, pane_id: u32) -> Result<u32, String> {
+        let panes = self.list_panes()?;
+
+        // Prefer non-plugin panes (plugin pane IDs can overlap with terminal pane IDs)
+        panes
+            .iter()
+            .find(|p| p.id == pane_id && !p.is_plugin)
+            .or_else(|| panes.iter().find(|p| p.id == pane_id))
+            .map(|p| p.tab_id)
+            .ok_or_else(|| format!("Pane ID {} not found in list-panes output", pane_id))
+    }
+
+    /// Get tab by tab_id via list_tabs
+    fn get_tab(&self, tab_id: u32) -> Result<TabEntry, String> {
+        let tabs = self.list_tabs()?;
+
+        tabs.into_iter()
+            .find(|t| t.tab_id == tab_id)
+            .ok_or_else(|| format!("Tab ID {} not found in list-tabs output", tab_id))
+    }
+}
+
+/// `ZellijApi` backed by `zellij action` subprocesses.
+pub struct Cli {
+    /// Session passed as `zellij --session <name>`; `None` uses the current one.
+    pub session: Option<String>,
+}
+
+impl Cli {
+    /// Returns `zellij [--session <name>] action` ready for action arguments.
+    fn action_command(&self) -> Command {
+        let mut cmd = Command::new(zellij_bin());
+        if let Some(session) = &self.session {
+            cmd.args(["--session", session]);
+        }
+        cmd.arg("action");
+        cmd
+    }
+
+    /// Run `zellij action <args>` and return its stdout
+    fn run_action(&self, args: &[&str]) -> Result<String, String> {
+        let cmd = format!("zellij action {}", args.join(" "));
+        let output = self
+            .action_command()
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run '{}': {}", cmd, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!(
+                "'{}' failed (exit {}): {}",
+                cmd, output.status, stderr
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl ZellijApi for Cli {
+    fn list_panes(&self) -> Result<Vec<PaneEntry>, String> {
+        let stdout = self.run_action(&["list-panes", "--json"])?;
+        serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse panes JSON: {}", e))
+    }
+
+    fn list_tabs(&self) -> Result<Vec<TabEntry>, String> {
+        let stdout = self.run_action(&["list-tabs", "--json"])?;
+        let mut tabs: Vec<TabEntry> = serde_json::from_str(&stdout)
+            .map_err(|e| format!("Failed to parse tabs JSON: {}", e))?;
+        target::fill_positions(&mut tabs);
+        Ok(tabs)
+    }
+
+    fn rename_tab(&self, tab_id: u32, new_name: &str) -> Result<(), String> {
+        self.run_action(&["rename-tab-by-id", &tab_id.to_string(), new_name])
+            .map(|_| ())
+    }
+
+    fn go_to_tab(&self, position: usize) -> Result<(), String> {
+        self.run_action(&["go-to-tab", &(position + 1).to_string()])
+            .map(|_| ())
+    }
 }
 
-/// Get tab name by tab_id via `zellij action list-tabs --json`
-pub fn get_tab_name(tab_id: u32) -> Result<String, String> {
-    let bin = zellij_bin();
-    let output = Command::new(&bin)
-        .args(["action", "list-tabs", "--json"])
-        .output()
-        .map_err(|e| format!("Failed to run 'zellij action list-tabs --json': {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!(
-            "'zellij action list-tabs --json' failed (exit {}): {}",
-            output.status, stderr
-        ));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let tabs: Vec<TabEntry> =
-        serde_json::from_str(&stdout).map_err(|e| format!("Failed to parse tabs JSON: {}", e))?;
-
-    tabs.iter()
-        .find(|t| t.tab_id == tab_id)
-        .map(|t| t.name.clone())
-        .ok_or_else(|| format!("Tab ID {} not found in list-tabs output", tab_id))
+/// Test double shared by the unit tests of modules that call Zellij.
+#[cfg(test)]
+pub(crate) mod fake {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Inactive tab as reported by list-tabs.
+    pub(crate) fn tab(tab_id: u32, name: &str, position: Option<usize>) -> TabEntry {
+        TabEntry {
+            tab_id,
+            name: name.to_string(),
+            position,
+            active: false,
+        }
+    }
+
+    /// In-memory session: renames and focus changes are applied and recorded.
+    pub(crate) struct FakeZellij {
+        pub(crate) panes: Vec<PaneEntry>,
+        pub(crate) tabs: RefCell<Vec<TabEntry>>,
+        pub(crate) calls: RefCell<Vec<String>>,
+    }
+
+    impl FakeZellij {
+        pub(crate) fn new(panes: &[(u32, u32, bool)], tabs: &[(u32, &str)]) -> Self {
+            FakeZellij {
+                panes: panes
+                    .iter()
+                    .map(|&(id, tab_id, is_plugin)| PaneEntry {
+                        id,
+                        tab_id,
+                        is_plugin,
+                        title: String::new(),
+                    })
+                    .collect(),
+                tabs: RefCell::new(
+                    tabs.iter()
+                        .enumerate()
+                        .map(|(i, &(tab_id, name))| TabEntry {
+                            active: i == 0,
+                            ..tab(tab_id, name, Some(i))
+                        })
+                        .collect(),
+                ),
+                calls: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl ZellijApi for FakeZellij {
+        fn list_panes(&self) -> Result<Vec<PaneEntry>, String> {
+            Ok(self.panes.clone())
+        }
+
+        fn list_tabs(&self) -> Result<Vec<TabEntry>, String> {
+            Ok(self.tabs.borrow().clone())
+        }
+
+        fn rename_tab(&self, tab_id: u32, new_name: &str) -> Result<(), String> {
+            self.calls
+                .borrow_mut()
+                .push(format!("rename-tab-by-id {} {}", tab_id, new_name));
+            let mut tabs = self.tabs.borrow_mut();
+            let tab = tabs
+                .iter_mut()
+                .find(|t| t.tab_id == tab_id)
+                .ok_or_else(|| format!("no tab {}", tab_id))?;
+            tab.name = new_name.to_string();
+            Ok(())
+        }
+
+        fn go_to_tab(&self, position: usize) -> Result<(), String> {
+            self.calls
+                .borrow_mut()
+                .push(format!("go-to-tab {}", position + 1));
+            for tab in self.tabs.borrow_mut().iter_mut() {
+                tab.active = tab.position == Some(position);
+            }
+            Ok(())
+        }
+    }
 }
 
-/// Rename tab by id via `zellij action rename-tab-by-id <id> <name>`
-pub fn rename_tab(tab_id: u32, new_name: &str) -> Result<(), String> {
-    let bin = zellij_bin();
-    let output = Command::new(&bin)
-        .args(["action", "rename-tab-by-id", &tab_id.to_string(), new_name])
-        .output()
-        .map_err(|e| format!("Failed to run 'zellij action rename-tab-by-id': {}", e))?;
+#[cfg(test)]
+mod tests {
+    use super::fake::FakeZellij;
+    use super::*;
+
+    #[test]
+    fn test_resolve_tab_id() {
+        let api = FakeZellij::new(&[(0, 0, false), (1, 4, false)], &[(0, "A"), (4, "B")]);
+        assert_eq!(api.resolve_tab_id(1), Ok(4));
+        assert!(api.resolve_tab_id(9).is_err());
+    }
+
+    #[test]
+    fn test_resolve_tab_id_prefers_terminal_pane() {
+        // Plugin pane 2 (e.g. the tab bar) shares its ID with terminal pane 2
+        let api = FakeZellij::new(&[(2, 0, true), (2, 4, false)], &[(0, "A"), (4, "B")]);
+        assert_eq!(api.resolve_tab_id(2), Ok(4));
+    }
+
+    #[test]
+    fn test_resolve_tab_id_plugin_only() {
+        let api = FakeZellij::new(&[(2, 0, true)], &[(0, "A")]);
+        assert_eq!(api.resolve_tab_id(2), Ok(0));
+    }
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!(
-            "'zellij action rename-tab-by-id {} \"{}\"' failed (exit {}): {}",
-            tab_id, new_name, output.status, stderr
-        ));
+    #[test]
+    fn test_get_tab() {
+        let api = FakeZellij::new(&[], &[(0, "A"), (4, "B")]);
+        assert_eq!(api.get_tab(4).map(|t| t.name), Ok("B".to_string()));
+        assert!(api.get_tab(5).is_err());
     }
 
-    Ok(())
+    #[test]
+    fn test_cli_session_args() {
+        let cli = Cli {
+            session: Some("work".to_string()),
+        };
+        let cmd = cli.action_command();
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(args, ["--session", "work", "action"]);
+
+        let cli = Cli { session: None };
+        let cmd = cli.action_command();
+        assert_eq!(cmd.get_args().collect::<Vec<_>>(), ["action"]);
+    }
 }